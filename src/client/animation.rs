@@ -0,0 +1,302 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use crate::client::assets::JsonAssetLoader;
+use crate::client::graphics::{Animation, SpriteFrame};
+use crate::shared::constants::{ANIMATION_FPS_DEFAULT, ANIMATION_FRAMES_DEFAULT};
+
+pub struct AnimationPlugin;
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<SpriteManifest>()
+            .register_asset_loader(JsonAssetLoader::<SpriteManifest>::default())
+            .add_event::<AnimationFinished>()
+            .add_systems(PreStartup, add_standard_manifest)
+            .add_systems(Update, play_sprite_animations);
+    }
+}
+
+/// Sprite manifest (`sprites/manifests/*.json`)
+/// 시트 레이아웃과 상태별 애니메이션 클립을 정의합니다.
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+pub struct SpriteManifest {
+    pub id: String,
+    pub name: String,
+    pub sprite_type: String,
+    pub image_path: String,
+    pub layout: SpriteLayout,
+    pub animations: Vec<AnimationClip>,
+    #[serde(default)]
+    pub mirror: MirrorRules,
+    #[serde(default)]
+    pub palettes: Vec<Palette>,
+    #[serde(default = "default_anchor")]
+    pub anchor: [f32; 2],
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SpriteLayout {
+    pub image_width: u32,
+    pub image_height: u32,
+    pub frame_width: u32,
+    pub frame_height: u32,
+    pub columns: usize,
+    pub rows: usize,
+    #[serde(default)]
+    pub padding: u32,
+    #[serde(default)]
+    pub offset_x: u32,
+    #[serde(default)]
+    pub offset_y: u32,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AnimationClip {
+    pub state: String,
+    pub start_frame: usize,
+    pub frame_count: usize,
+    pub fps: f32,
+    pub looping: bool,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct MirrorRules {
+    pub enabled: bool,
+    #[serde(default)]
+    pub source_to_target: HashMap<String, String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Palette {
+    pub name: String,
+    pub color_map: HashMap<String, String>,
+}
+
+impl MirrorRules {
+    /// Direction whose frames are drawn flipped to produce `direction`
+    pub fn source_for(&self, direction: &str) -> Option<&str> {
+        if !self.enabled {
+            return None;
+        }
+        self.source_to_target
+            .iter()
+            .find(|(_, target)| target.as_str() == direction)
+            .map(|(source, _)| source.as_str())
+    }
+}
+
+fn default_anchor() -> [f32; 2] {
+    [0.5, 0.5]
+}
+
+impl SpriteManifest {
+    /// Standard 4x4 sheet (ASSETS.md): rows are Idle/Walk/Attack/Die.
+    /// Used for sheets that ship without a manifest file.
+    pub fn standard() -> Self {
+        let columns = ANIMATION_FRAMES_DEFAULT;
+        let clip = |state: &str, row: usize, looping: bool| AnimationClip {
+            state: state.to_string(),
+            start_frame: row * columns,
+            frame_count: columns,
+            fps: ANIMATION_FPS_DEFAULT,
+            looping,
+        };
+
+        Self {
+            id: "standard".to_string(),
+            name: "Standard 4x4".to_string(),
+            sprite_type: "standard".to_string(),
+            image_path: String::new(),
+            layout: SpriteLayout {
+                image_width: 0,
+                image_height: 0,
+                frame_width: 0,
+                frame_height: 0,
+                columns,
+                rows: 4,
+                padding: 0,
+                offset_x: 0,
+                offset_y: 0,
+            },
+            animations: vec![
+                clip("idle", 0, true),
+                clip("walk", 1, true),
+                clip("attack", 2, false),
+                clip("die", 3, false),
+            ],
+            mirror: MirrorRules::default(),
+            palettes: Vec::new(),
+            anchor: default_anchor(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    pub fn clip(&self, state: &str) -> Option<&AnimationClip> {
+        self.animations.iter().find(|clip| clip.state == state)
+    }
+
+    /// `image_path` is written as a web path ("/assets/..."), the asset server wants it relative
+    pub fn texture_path(&self) -> &str {
+        self.image_path
+            .trim_start_matches('/')
+            .trim_start_matches("assets/")
+    }
+}
+
+/// Manifest for sheets without their own manifest file
+#[derive(Resource)]
+pub struct StandardSpriteManifest(pub Handle<SpriteManifest>);
+
+fn add_standard_manifest(mut commands: Commands, mut manifests: ResMut<Assets<SpriteManifest>>) {
+    let handle = manifests.add(SpriteManifest::standard());
+    commands.insert_resource(StandardSpriteManifest(handle));
+}
+
+/// Plays named states ("idle", "walk", "attack", "die") from a [`SpriteManifest`]
+/// and writes the selected frame into the sibling [`Animation`] component.
+/// Direction comes from a [`Facing`] on the same entity or its parent; directions listed
+/// as mirror targets in the manifest are drawn flipped.
+#[derive(Component)]
+pub struct SpriteAnimator {
+    pub manifest: Handle<SpriteManifest>,
+    pub state: String,
+    pub frame: usize,
+    pub timer: Timer,
+    pub finished: bool,
+}
+
+impl SpriteAnimator {
+    pub fn new(manifest: Handle<SpriteManifest>, state: &str) -> Self {
+        Self {
+            manifest,
+            state: state.to_string(),
+            frame: 0,
+            timer: Timer::from_seconds(1.0, TimerMode::Repeating),
+            finished: false,
+        }
+    }
+
+    /// Switch to another state. Replaying the current state is a no-op so this
+    /// can be called every frame from controllers.
+    pub fn play(&mut self, state: &str) {
+        if self.state != state {
+            self.restart(state);
+        }
+    }
+
+    /// Start a state from its first frame, even if it is already playing (e.g. repeated attacks)
+    pub fn restart(&mut self, state: &str) {
+        self.state = state.to_string();
+        self.frame = 0;
+        self.timer.reset();
+        self.finished = false;
+    }
+}
+
+/// Facing direction index: Down=0, Left=1, Right=2, Up=3 (ASSETS.md 방향 순서)
+#[derive(Component, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Facing(pub usize);
+
+impl Facing {
+    pub const DOWN: Self = Self(0);
+    pub const LEFT: Self = Self(1);
+    pub const RIGHT: Self = Self(2);
+    pub const UP: Self = Self(3);
+
+    /// Closest of the four sheet directions for a movement on the XZ plane
+    pub fn from_direction(direction: Vec3) -> Self {
+        if direction.z.abs() > direction.x.abs() {
+            if direction.z > 0.0 { Self::DOWN } else { Self::UP } // Down (+Z) / Up (-Z)
+        } else if direction.x < 0.0 {
+            Self::LEFT // Left (-X)
+        } else {
+            Self::RIGHT // Right (+X)
+        }
+    }
+
+    /// Unit vector on the XZ plane this direction points to
+    pub fn direction(self) -> Vec3 {
+        match self.0 {
+            1 => Vec3::NEG_X,
+            2 => Vec3::X,
+            3 => Vec3::NEG_Z,
+            _ => Vec3::Z,
+        }
+    }
+
+    /// Direction name as used by manifests and anchor files
+    pub fn name(self) -> &'static str {
+        match self.0 {
+            1 => "left",
+            2 => "right",
+            3 => "up",
+            _ => "down",
+        }
+    }
+}
+
+/// Sent once when a non-looping clip reaches its last frame
+#[derive(Event, Debug, Clone)]
+pub struct AnimationFinished {
+    pub entity: Entity,
+    pub state: String,
+}
+
+#[allow(clippy::type_complexity)]
+fn play_sprite_animations(
+    time: Res<Time>,
+    manifests: Res<Assets<SpriteManifest>>,
+    mut query: Query<(Entity, &mut SpriteAnimator, &mut Animation, Option<&Facing>, Option<&Parent>)>,
+    facing_q: Query<&Facing>,
+    mut finished_events: EventWriter<AnimationFinished>,
+) {
+    for (entity, mut animator, mut animation, facing, parent) in query.iter_mut() {
+        let Some(manifest) = manifests.get(&animator.manifest) else {
+            continue;
+        };
+        let Some(clip) = manifest.clip(&animator.state) else {
+            continue;
+        };
+
+        let facing = facing
+            .or_else(|| parent.and_then(|parent| facing_q.get(parent.get()).ok()))
+            .copied()
+            .unwrap_or_default();
+
+        let frame_duration = std::time::Duration::from_secs_f32(1.0 / clip.fps.max(0.001));
+        if animator.timer.duration() != frame_duration {
+            animator.timer.set_duration(frame_duration);
+        }
+
+        if !animator.finished {
+            animator.timer.tick(time.delta());
+            for _ in 0..animator.timer.times_finished_this_tick() {
+                if animator.frame + 1 < clip.frame_count {
+                    animator.frame += 1;
+                } else if clip.looping {
+                    animator.frame = 0;
+                } else {
+                    animator.finished = true;
+                    finished_events.send(AnimationFinished {
+                        entity,
+                        state: animator.state.clone(),
+                    });
+                    break;
+                }
+            }
+        }
+
+        let index = clip.start_frame + animator.frame.min(clip.frame_count.saturating_sub(1));
+        // The animator owns frame selection; graphics.rs only re-uploads when this differs
+        Animation::set_sprite_frame(&mut animation, SpriteFrame {
+            rows: manifest.layout.rows,
+            cols: manifest.layout.columns,
+            row: index / manifest.layout.columns,
+            col: index % manifest.layout.columns,
+            flip_x: manifest.mirror.source_for(facing.name()).is_some(),
+        });
+    }
+}
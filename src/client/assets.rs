@@ -0,0 +1,45 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use thiserror::Error;
+
+/// Loads any serde-deserializable asset from a `.json` file.
+/// Several loaders share the extension; the asset server picks one by the requested asset type.
+pub struct JsonAssetLoader<A>(PhantomData<fn() -> A>);
+
+impl<A> Default for JsonAssetLoader<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum JsonAssetLoaderError {
+    #[error("Could not read JSON asset: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse JSON asset: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+impl<A: Asset + DeserializeOwned> AssetLoader for JsonAssetLoader<A> {
+    type Asset = A;
+    type Settings = ();
+    type Error = JsonAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["json"]
+    }
+}
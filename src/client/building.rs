@@ -1,28 +1,437 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use std::collections::HashMap;
+use thiserror::Error;
+use crate::client::camera::cursor_ground_position;
+use crate::client::combat::{DeathEvent, Health};
+use crate::client::enemy::{Dying, Enemy};
 use crate::client::game::GameResources;
+use crate::client::graphics::{create_sprite_material, create_sprite_mesh};
+use crate::client::map::{Decoration, MapEntity, ResourceNode, tile_at, tile_corner};
+use crate::client::navigation::NavObstacle;
+use crate::client::player::Player;
+use crate::client::tilemap::GroundTiles;
+use crate::client::walls::WALL_PILLAR_FRACTION;
+use crate::shared::constants::TILE_WORLD_SIZE;
 
 pub struct BuildingPlugin;
 
 impl Plugin for BuildingPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (toggle_build_mode, update_ghost, place_building));
+        app.init_resource::<TileOccupancy>();
+        app.add_systems(Startup, setup_build_ui);
+        app.add_systems(Update, (
+            (toggle_build_mode, select_building_type, build_hotkeys, update_ghost).chain(),
+            update_build_menu,
+            place_building,
+            demolish_building,
+            highlight_demolish_target,
+            tint_damaged_buildings,
+            destroy_buildings,
+            release_tiles,
+        ));
     }
 }
 
 #[derive(Component)]
-pub struct Building;
+pub struct Building {
+    pub building_type: BuildingType,
+    /// -X/-Z tile of the footprint
+    pub tile: IVec2,
+    /// Quarter turns around Y
+    pub rotation: u8,
+}
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum BuildingType {
     Wall,
     Base,
+    House,
+    Inn,
+    Shop,
+    Temple,
+    Tower,
+    Blacksmith,
+}
+
+/// Resources spent to place a building
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct BuildCost {
+    pub wood: u32,
+    pub gold: u32,
+}
+
+impl BuildCost {
+    pub fn check(self, resources: &GameResources) -> Result<(), PlacementError> {
+        if resources.wood < self.wood {
+            return Err(PlacementError::NotEnoughWood { need: self.wood, have: resources.wood });
+        }
+        if resources.gold < self.gold {
+            return Err(PlacementError::NotEnoughGold { need: self.gold, have: resources.gold });
+        }
+        Ok(())
+    }
+
+    pub fn pay(self, resources: &mut GameResources) {
+        resources.wood -= self.wood;
+        resources.gold -= self.gold;
+    }
+
+    /// Cost of `count` buildings
+    pub fn times(self, count: u32) -> BuildCost {
+        BuildCost {
+            wood: self.wood * count,
+            gold: self.gold * count,
+        }
+    }
+
+    /// What demolishing gives back
+    pub fn refund(self) -> BuildCost {
+        BuildCost {
+            wood: (self.wood as f32 * DEMOLISH_REFUND_FRACTION) as u32,
+            gold: (self.gold as f32 * DEMOLISH_REFUND_FRACTION) as u32,
+        }
+    }
+
+    pub fn add_to(self, resources: &mut GameResources) {
+        resources.wood += self.wood;
+        resources.gold += self.gold;
+    }
+}
+
+impl std::fmt::Display for BuildCost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.wood, self.gold) {
+            (wood, 0) => write!(f, "{wood}W"),
+            (0, gold) => write!(f, "{gold}G"),
+            (wood, gold) => write!(f, "{wood}W {gold}G"),
+        }
+    }
 }
 
+impl BuildingType {
+    /// Build menu order; the n-th entry is selected with digit key n
+    pub const ALL: [BuildingType; 8] = [
+        BuildingType::Wall,
+        BuildingType::Base,
+        BuildingType::House,
+        BuildingType::Inn,
+        BuildingType::Shop,
+        BuildingType::Temple,
+        BuildingType::Tower,
+        BuildingType::Blacksmith,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            BuildingType::Wall => "Wall",
+            BuildingType::Base => "Base",
+            BuildingType::House => "House",
+            BuildingType::Inn => "Inn",
+            BuildingType::Shop => "Shop",
+            BuildingType::Temple => "Temple",
+            BuildingType::Tower => "Tower",
+            BuildingType::Blacksmith => "Blacksmith",
+        }
+    }
+
+    pub fn cost(self) -> BuildCost {
+        let (wood, gold) = match self {
+            BuildingType::Wall => (20, 0),
+            BuildingType::Base => (100, 50),
+            BuildingType::House => (50, 0),
+            BuildingType::Inn => (80, 30),
+            BuildingType::Shop => (60, 40),
+            BuildingType::Temple => (120, 100),
+            BuildingType::Tower => (60, 30),
+            BuildingType::Blacksmith => (80, 50),
+        };
+        BuildCost { wood, gold }
+    }
+
+    pub fn max_health(self) -> u32 {
+        match self {
+            BuildingType::Wall => 300,
+            BuildingType::Base => 1000,
+            BuildingType::House => 400,
+            BuildingType::Inn => 500,
+            BuildingType::Shop => 450,
+            BuildingType::Temple => 800,
+            BuildingType::Tower => 350,
+            BuildingType::Blacksmith => 500,
+        }
+    }
+
+    /// Size in tiles (X, Z) before rotation
+    pub fn footprint(self) -> IVec2 {
+        match self {
+            BuildingType::Wall | BuildingType::Tower => IVec2::ONE,
+            BuildingType::Temple => IVec2::splat(3),
+            _ => IVec2::splat(2),
+        }
+    }
+
+    /// Footprint after `rotation` quarter turns (odd turns swap X and Z)
+    pub fn rotated_footprint(self, rotation: u8) -> IVec2 {
+        let footprint = self.footprint();
+        if rotation % 2 == 1 { IVec2::new(footprint.y, footprint.x) } else { footprint }
+    }
+
+    /// Billboard texture (`buildings/*.png`). Types without one are drawn as blocks.
+    pub fn sprite(self) -> Option<&'static str> {
+        match self {
+            BuildingType::Wall | BuildingType::Base => None,
+            BuildingType::House => Some("buildings/house.png"),
+            BuildingType::Inn => Some("buildings/inn.png"),
+            BuildingType::Shop => Some("buildings/shop.png"),
+            BuildingType::Temple => Some("buildings/temple.png"),
+            BuildingType::Tower => Some("buildings/tower.png"),
+            BuildingType::Blacksmith => Some("buildings/blacksmith.png"),
+        }
+    }
+
+    /// Colour at full health (multiplies the texture for sprite buildings)
+    pub fn color(self) -> Color {
+        match self {
+            BuildingType::Wall => Color::srgb(0.5, 0.5, 0.5),
+            BuildingType::Base => Color::srgb(0.55, 0.45, 0.3),
+            _ => Color::WHITE,
+        }
+    }
+
+    /// Mesh sized to the footprint: a block, or a quad as wide as the footprint.
+    /// Walls are a pillar; `walls.rs` adds connectors towards neighbouring walls.
+    pub fn mesh(self, meshes: &mut Assets<Mesh>) -> Handle<Mesh> {
+        let size = self.footprint().as_vec2() * TILE_WORLD_SIZE;
+        match self.sprite() {
+            Some(_) => create_sprite_mesh(meshes, Vec2::splat(size.x)),
+            None if self == BuildingType::Wall => {
+                let pillar = size * WALL_PILLAR_FRACTION;
+                meshes.add(Cuboid::new(pillar.x, BUILDING_HEIGHT, pillar.y))
+            }
+            None => meshes.add(Cuboid::new(size.x, BUILDING_HEIGHT, size.y)),
+        }
+    }
+
+    /// Fresh material tinted `color`, so damage/ghost tints stay per entity
+    pub fn material(
+        self,
+        color: Color,
+        materials: &mut Assets<StandardMaterial>,
+        asset_server: &AssetServer,
+    ) -> Handle<StandardMaterial> {
+        let handle = match self.sprite() {
+            Some(path) => create_sprite_material(materials, asset_server.load(path), AlphaMode::Blend),
+            None => materials.add(StandardMaterial {
+                alpha_mode: if color.alpha() < 1.0 { AlphaMode::Blend } else { AlphaMode::Opaque },
+                ..default()
+            }),
+        };
+        if let Some(material) = materials.get_mut(&handle) {
+            material.base_color = color;
+        }
+        handle
+    }
+
+    /// Where the building mesh sits for a footprint placement.
+    /// Sprites always face the camera, so rotation only turns their footprint;
+    /// walls are symmetric and take their shape from their neighbours instead.
+    pub fn transform(self, placement: &Placement, rotation: u8) -> Transform {
+        match self.sprite() {
+            // Same tilt as trees and characters
+            Some(_) => Transform::from_translation(placement.center() + Vec3::Y * placement.world_size().x * 0.5)
+                .with_rotation(Quat::from_rotation_x(-45.0f32.to_radians())),
+            None if self == BuildingType::Wall => {
+                Transform::from_translation(placement.center() + Vec3::Y * BUILDING_HEIGHT * 0.5)
+            }
+            None => Transform::from_translation(placement.center() + Vec3::Y * BUILDING_HEIGHT * 0.5)
+                .with_rotation(Quat::from_rotation_y(rotation as f32 * std::f32::consts::FRAC_PI_2)),
+        }
+    }
+}
+
+/// Colour a building fades to as it loses health
+const DAMAGED_BUILDING_COLOR: Color = Color::srgb(0.45, 0.1, 0.08);
+
+/// Height of block buildings (walls, base)
+pub const BUILDING_HEIGHT: f32 = 2.0;
+
+/// Share of the build cost returned when demolishing
+const DEMOLISH_REFUND_FRACTION: f32 = 0.5;
+
+const DEMOLISH_HIGHLIGHT_COLOR: Color = Color::srgb(1.0, 0.25, 0.2);
+
+/// Ground radius of the player/enemies when checking if a spot is free
+const UNIT_RADIUS: f32 = 0.4;
+
+/// Half size of a resource node's footprint (tree trunk, rock)
+const RESOURCE_NODE_HALF_SIZE: f32 = 0.5;
+
+const GHOST_VALID_COLOR: Color = Color::srgba(0.3, 1.0, 0.3, 0.5);
+const GHOST_INVALID_COLOR: Color = Color::srgba(1.0, 0.3, 0.3, 0.5);
+
+/// Which building owns each tile
+#[derive(Resource, Default)]
+pub struct TileOccupancy {
+    tiles: HashMap<IVec2, Entity>,
+}
+
+impl TileOccupancy {
+    pub fn get(&self, tile: IVec2) -> Option<Entity> {
+        self.tiles.get(&tile).copied()
+    }
+
+    pub fn is_free(&self, tile: IVec2) -> bool {
+        !self.tiles.contains_key(&tile)
+    }
+}
+
+/// Why a building can't go where the cursor is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum PlacementError {
+    #[error("Outside the map")]
+    OutOfBounds,
+    #[error("Another building is in the way")]
+    Occupied,
+    #[error("Can't build on water or lava")]
+    BlockedByTerrain,
+    #[error("Something is in the way")]
+    BlockedByDecoration,
+    #[error("A tree or rock is in the way")]
+    BlockedByResource,
+    #[error("The player is standing there")]
+    BlockedByPlayer,
+    #[error("An enemy is standing there")]
+    BlockedByEnemy,
+    #[error("Not enough wood ({have}/{need})")]
+    NotEnoughWood { need: u32, have: u32 },
+    #[error("Not enough gold ({have}/{need})")]
+    NotEnoughGold { need: u32, have: u32 },
+}
+
+/// Tiles a building of `footprint` would cover with the cursor over `cursor_tile`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    pub tile: IVec2,
+    pub footprint: IVec2,
+}
+
+impl Placement {
+    /// Footprint centred on the cursor tile (even sizes lean towards -X/-Z)
+    pub fn around(cursor_tile: IVec2, footprint: IVec2) -> Self {
+        Self {
+            tile: cursor_tile - (footprint - IVec2::ONE) / 2,
+            footprint,
+        }
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = IVec2> + '_ {
+        (0..self.footprint.y).flat_map(move |z| (0..self.footprint.x).map(move |x| self.tile + IVec2::new(x, z)))
+    }
+
+    /// Size on the ground in world units
+    pub fn world_size(&self) -> Vec2 {
+        self.footprint.as_vec2() * TILE_WORLD_SIZE
+    }
+
+    /// Ground position of the footprint centre
+    pub fn center(&self) -> Vec3 {
+        let size = self.world_size();
+        tile_corner(self.tile) + Vec3::new(size.x * 0.5, 0.0, size.y * 0.5)
+    }
+
+    /// Does a circle on the ground overlap the footprint?
+    fn overlaps(&self, position: Vec3, radius: f32) -> bool {
+        self.overlaps_area(position, Vec2::splat(radius))
+    }
+
+    /// Does a rectangle with half extents `half_size`, centred on `position`, overlap the footprint?
+    fn overlaps_area(&self, position: Vec3, half_size: Vec2) -> bool {
+        let half = self.world_size() * 0.5 + half_size;
+        let offset = (position - self.center()).abs();
+        offset.x < half.x && offset.z < half.y
+    }
+}
+
+/// Resource nodes, scenery and units that may stand where a building would go
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub struct PlacementCheck<'w, 's> {
+    resource_q: Query<'w, 's, &'static Transform, (With<ResourceNode>, Without<BuildGhost>)>,
+    player_q: Query<'w, 's, &'static Transform, (With<Player>, Without<BuildGhost>)>,
+    enemy_q: Query<'w, 's, &'static Transform, (With<Enemy>, Without<Dying>, Without<BuildGhost>)>,
+    decoration_q: Query<'w, 's, (&'static Transform, &'static Decoration), Without<BuildGhost>>,
+    ground: Option<Res<'w, GroundTiles>>,
+}
+
+impl PlacementCheck<'_, '_> {
+    /// Is the ground under `placement` free? (Cost is checked separately with [`BuildCost::check`].)
+    pub fn validate(&self, placement: &Placement, occupancy: &TileOccupancy) -> Result<(), PlacementError> {
+        for tile in placement.tiles() {
+            let Some(ground) = self.ground.as_ref().filter(|ground| ground.in_bounds(tile.x, tile.y)) else {
+                return Err(PlacementError::OutOfBounds);
+            };
+            if !occupancy.is_free(tile) {
+                return Err(PlacementError::Occupied);
+            }
+            if !ground.is_walkable(tile.x, tile.y) {
+                return Err(PlacementError::BlockedByTerrain);
+            }
+        }
+        if self.resource_q.iter().any(|node| placement.overlaps(node.translation, RESOURCE_NODE_HALF_SIZE)) {
+            return Err(PlacementError::BlockedByResource);
+        }
+        let blocked_by_decoration = self.decoration_q.iter().any(|(transform, decoration)| {
+            decoration.footprint != Vec2::ZERO && placement.overlaps_area(transform.translation, decoration.footprint * 0.5)
+        });
+        if blocked_by_decoration {
+            return Err(PlacementError::BlockedByDecoration);
+        }
+        if self.player_q.iter().any(|player| placement.overlaps(player.translation, UNIT_RADIUS)) {
+            return Err(PlacementError::BlockedByPlayer);
+        }
+        if self.enemy_q.iter().any(|enemy| placement.overlaps(enemy.translation, UNIT_RADIUS)) {
+            return Err(PlacementError::BlockedByEnemy);
+        }
+        Ok(())
+    }
+}
+
+/// Translucent preview of the building under the cursor
+#[derive(Component)]
+pub struct BuildGhost;
+
+/// Shows why the ghost is red
+#[derive(Component)]
+struct BuildHintText;
+
+/// Build menu bar, shown while in build mode
+#[derive(Component)]
+struct BuildMenu;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+enum BuildMenuButton {
+    Building(BuildingType),
+    Demolish,
+}
+
+const MENU_BUTTON_COLOR: Color = Color::srgba(0.1, 0.1, 0.1, 0.8);
+const MENU_BUTTON_HOVER_COLOR: Color = Color::srgba(0.25, 0.25, 0.25, 0.9);
+const MENU_BUTTON_SELECTED_COLOR: Color = Color::srgba(0.2, 0.45, 0.2, 0.9);
+
 #[derive(Resource)]
 pub struct BuildMode {
     pub active: bool,
     pub building_type: BuildingType,
     pub ghost_entity: Option<Entity>,
+    /// Quarter turns applied to the next placement (R)
+    pub rotation: u8,
+    /// Clicking removes buildings instead of placing (X)
+    pub demolish: bool,
+    /// Tile where the current wall drag started
+    pub drag_start: Option<IVec2>,
+    /// Extra ghosts previewing a dragged wall line
+    pub line_ghosts: Vec<Entity>,
 }
 
 impl Default for BuildMode {
@@ -31,121 +440,580 @@ impl Default for BuildMode {
             active: false,
             building_type: BuildingType::Wall,
             ghost_entity: None,
+            rotation: 0,
+            demolish: false,
+            drag_start: None,
+            line_ghosts: Vec::new(),
         }
     }
 }
 
+impl BuildMode {
+    /// Despawn the ghost and any line preview
+    fn clear_ghosts(&mut self, commands: &mut Commands) {
+        if let Some(entity) = self.ghost_entity.take() {
+            commands.entity(entity).despawn_recursive();
+        }
+        for entity in self.line_ghosts.drain(..) {
+            commands.entity(entity).despawn_recursive();
+        }
+        self.drag_start = None;
+    }
+
+    /// Keep exactly `count` extra ghosts, sharing the main ghost's mesh and material
+    fn resize_line_ghosts(
+        &mut self,
+        commands: &mut Commands,
+        count: usize,
+        mesh: &Handle<Mesh>,
+        material: &Handle<StandardMaterial>,
+    ) {
+        while self.line_ghosts.len() > count {
+            if let Some(entity) = self.line_ghosts.pop() {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+        while self.line_ghosts.len() < count {
+            self.line_ghosts.push(commands.spawn((
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::from_xyz(0.0, -10.0, 0.0), // Hide initially
+                BuildGhost,
+            )).id());
+        }
+    }
+}
+
+fn setup_build_ui(mut commands: Commands) {
+    commands
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(56.0),
+            left: Val::Px(10.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.5, 0.4)),
+                BuildHintText,
+            ));
+        });
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.0),
+                left: Val::Px(10.0),
+                column_gap: Val::Px(4.0),
+                ..default()
+            },
+            Visibility::Hidden,
+            BuildMenu,
+        ))
+        .with_children(|parent| {
+            for (index, building_type) in BuildingType::ALL.into_iter().enumerate() {
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            padding: UiRect::axes(Val::Px(8.0), Val::Px(6.0)),
+                            ..default()
+                        },
+                        BackgroundColor(MENU_BUTTON_COLOR),
+                        BuildMenuButton::Building(building_type),
+                    ))
+                    .with_children(|button| {
+                        button.spawn((
+                            Text::new(format!("{} {} ({})", index + 1, building_type.name(), building_type.cost())),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+            }
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(MENU_BUTTON_COLOR),
+                    BuildMenuButton::Demolish,
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        Text::new("X Demolish"),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+        });
+}
+
+/// Translucent copy of the selected building, drawn under the cursor
+fn spawn_ghost(
+    commands: &mut Commands,
+    building_type: BuildingType,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    asset_server: &AssetServer,
+) -> Entity {
+    commands.spawn((
+        Mesh3d(building_type.mesh(meshes)),
+        MeshMaterial3d(building_type.material(GHOST_VALID_COLOR, materials, asset_server)),
+        Transform::from_xyz(0.0, -10.0, 0.0), // Hide initially
+        BuildGhost,
+    )).id()
+}
+
+#[allow(clippy::too_many_arguments)]
 fn toggle_build_mode(
     mut commands: Commands,
     keys: Res<ButtonInput<KeyCode>>,
     mut build_mode: ResMut<BuildMode>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+    mut hint_q: Query<&mut Text, With<BuildHintText>>,
+    mut menu_q: Query<&mut Visibility, With<BuildMenu>>,
 ) {
     if keys.just_pressed(KeyCode::KeyB) {
         build_mode.active = !build_mode.active;
 
+        for mut visibility in menu_q.iter_mut() {
+            *visibility = if build_mode.active { Visibility::Inherited } else { Visibility::Hidden };
+        }
+
+        build_mode.demolish = false;
+
         // Despawn ghost if disabling
         if !build_mode.active {
-            if let Some(entity) = build_mode.ghost_entity {
-                commands.entity(entity).despawn_recursive();
-                build_mode.ghost_entity = None;
+            build_mode.clear_ghosts(&mut commands);
+            for mut text in hint_q.iter_mut() {
+                text.0.clear();
             }
         } else {
              // Spawn ghost if enabling
-             let mesh = meshes.add(Cuboid::new(1.0, 2.0, 1.0));
-             let material = materials.add(StandardMaterial {
-                 base_color: Color::srgba(0.5, 0.5, 1.0, 0.5),
-                 alpha_mode: AlphaMode::Blend,
-                 ..default()
-             });
-
-             let ghost = commands.spawn((
-                 Mesh3d(mesh),
-                 MeshMaterial3d(material),
-                 Transform::from_xyz(0.0, -10.0, 0.0), // Hide initially
-             )).id();
-
+             let ghost = spawn_ghost(&mut commands, build_mode.building_type, &mut meshes, &mut materials, &asset_server);
              build_mode.ghost_entity = Some(ghost);
         }
     }
 }
 
-fn update_ghost(
+/// Digit keys or the menu bar pick the building type
+fn select_building_type(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut build_mode: ResMut<BuildMode>,
+    button_q: Query<(&Interaction, &BuildMenuButton), Changed<Interaction>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+) {
+    if !build_mode.active {
+        return;
+    }
+
+    const DIGITS: [KeyCode; 8] = [
+        KeyCode::Digit1,
+        KeyCode::Digit2,
+        KeyCode::Digit3,
+        KeyCode::Digit4,
+        KeyCode::Digit5,
+        KeyCode::Digit6,
+        KeyCode::Digit7,
+        KeyCode::Digit8,
+    ];
+    let mut selected = DIGITS
+        .iter()
+        .zip(BuildingType::ALL)
+        .find(|(key, _)| keys.just_pressed(**key))
+        .map(|(_, building_type)| building_type);
+    for (interaction, button) in button_q.iter() {
+        match (interaction, button) {
+            (Interaction::Pressed, BuildMenuButton::Building(building_type)) => selected = Some(*building_type),
+            (Interaction::Pressed, BuildMenuButton::Demolish) => build_mode.demolish = true,
+            _ => {}
+        }
+    }
+
+    let Some(building_type) = selected else {
+        return;
+    };
+    // Picking a building leaves demolish mode
+    build_mode.demolish = false;
+    if building_type == build_mode.building_type && build_mode.ghost_entity.is_some() {
+        return;
+    }
+
+    build_mode.building_type = building_type;
+    build_mode.clear_ghosts(&mut commands);
+    build_mode.ghost_entity = Some(spawn_ghost(&mut commands, building_type, &mut meshes, &mut materials, &asset_server));
+}
+
+/// R rotates the ghost, X toggles demolish mode
+fn build_hotkeys(keys: Res<ButtonInput<KeyCode>>, mut build_mode: ResMut<BuildMode>) {
+    if !build_mode.active {
+        return;
+    }
+    if keys.just_pressed(KeyCode::KeyR) {
+        build_mode.rotation = (build_mode.rotation + 1) % 4;
+    }
+    if keys.just_pressed(KeyCode::KeyX) {
+        build_mode.demolish = !build_mode.demolish;
+    }
+}
+
+fn update_build_menu(
     build_mode: Res<BuildMode>,
+    mut button_q: Query<(&Interaction, &BuildMenuButton, &mut BackgroundColor)>,
+) {
+    for (interaction, button, mut background) in button_q.iter_mut() {
+        let selected = match button {
+            BuildMenuButton::Building(building_type) => !build_mode.demolish && *building_type == build_mode.building_type,
+            BuildMenuButton::Demolish => build_mode.demolish,
+        };
+        let color = if selected {
+            MENU_BUTTON_SELECTED_COLOR
+        } else if *interaction == Interaction::Hovered {
+            MENU_BUTTON_HOVER_COLOR
+        } else {
+            MENU_BUTTON_COLOR
+        };
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
+
+/// Tiles from `start` towards `end` along whichever grid axis the drag moved further on
+pub fn wall_line(start: IVec2, end: IVec2) -> Vec<IVec2> {
+    let delta = end - start;
+    let (step, length) = if delta.x.abs() >= delta.y.abs() {
+        (IVec2::new(delta.x.signum(), 0), delta.x.abs())
+    } else {
+        (IVec2::new(0, delta.y.signum()), delta.y.abs())
+    };
+    (0..=length).map(|i| start + step * i).collect()
+}
+
+/// Footprints the current tool would build: a dragged wall line or a single building
+fn planned_placements(build_mode: &BuildMode, cursor_tile: IVec2) -> Vec<Placement> {
+    match build_mode.drag_start {
+        Some(start) => wall_line(start, cursor_tile)
+            .into_iter()
+            .map(|tile| Placement::around(tile, IVec2::ONE))
+            .collect(),
+        None => vec![Placement::around(
+            cursor_tile,
+            build_mode.building_type.rotated_footprint(build_mode.rotation),
+        )],
+    }
+}
+
+/// All or nothing: every footprint must be free and the whole line affordable
+fn validate_placements(
+    placements: &[Placement],
+    building_type: BuildingType,
+    check: &PlacementCheck,
+    occupancy: &TileOccupancy,
+    resources: &GameResources,
+) -> Result<BuildCost, PlacementError> {
+    for placement in placements {
+        check.validate(placement, occupancy)?;
+    }
+    let cost = building_type.cost().times(placements.len() as u32);
+    cost.check(resources)?;
+    Ok(cost)
+}
+
+/// Snap the ghost to the tile grid and tint it by whether the spot is valid.
+/// While dragging a wall line, extra ghosts preview every segment.
+#[allow(clippy::too_many_arguments)]
+fn update_ghost(
+    mut commands: Commands,
+    mut build_mode: ResMut<BuildMode>,
     windows: Query<&Window>,
     camera_q: Query<(&Camera, &GlobalTransform), With<crate::client::camera::MainCamera>>,
-    mut ghost_q: Query<&mut Transform>,
+    mut ghost_q: Query<(&mut Transform, &mut Visibility, &Mesh3d, &MeshMaterial3d<StandardMaterial>), With<BuildGhost>>,
+    mut hint_q: Query<&mut Text, With<BuildHintText>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    check: PlacementCheck,
+    occupancy: Res<TileOccupancy>,
+    game_resources: Res<GameResources>,
 ) {
     if !build_mode.active {
         return;
     }
+    let Some(ghost_entity) = build_mode.ghost_entity else {
+        return;
+    };
+    let Ok((_, _, mesh, material)) = ghost_q.get(ghost_entity) else {
+        return;
+    };
+    let (mesh, material) = (mesh.0.clone(), material.0.clone());
 
-    if let Some(ghost_entity) = build_mode.ghost_entity {
-        if let Ok(mut transform) = ghost_q.get_mut(ghost_entity) {
-            let (camera, camera_transform) = camera_q.single();
-            let window = windows.single();
+    // The demolish tool has no ghost; its hint is written by highlight_demolish_target
+    if build_mode.demolish {
+        build_mode.resize_line_ghosts(&mut commands, 0, &mesh, &material);
+        if let Ok((_, mut visibility, _, _)) = ghost_q.get_mut(ghost_entity) {
+            *visibility = Visibility::Hidden;
+        }
+        return;
+    }
+
+    let (camera, camera_transform) = camera_q.single();
+    let window = windows.single();
 
-            if let Some(cursor_position) = window.cursor_position() {
-                if let Ok(ray) = camera.viewport_to_world(camera_transform, cursor_position) {
-                    if ray.direction.y.abs() > f32::EPSILON {
-                        let t = -ray.origin.y / ray.direction.y;
-                        if t >= 0.0 {
-                            let target_pos = ray.origin + ray.direction * t;
-                            transform.translation = Vec3::new(target_pos.x, 1.0, target_pos.z);
-                        }
-                    }
-                }
+    if let Some(target_pos) = cursor_ground_position(window, camera, camera_transform) {
+        let building_type = build_mode.building_type;
+        let placements = planned_placements(&build_mode, tile_at(target_pos));
+        build_mode.resize_line_ghosts(&mut commands, placements.len() - 1, &mesh, &material);
+
+        let ghosts = std::iter::once(ghost_entity).chain(build_mode.line_ghosts.iter().copied());
+        for (ghost, placement) in ghosts.zip(&placements) {
+            // Freshly spawned line ghosts get positioned next frame
+            if let Ok((mut transform, mut visibility, _, _)) = ghost_q.get_mut(ghost) {
+                *transform = building_type.transform(placement, build_mode.rotation);
+                *visibility = Visibility::Inherited;
             }
         }
+
+        let result = validate_placements(&placements, building_type, &check, &occupancy, &game_resources);
+        if let Some(material) = materials.get_mut(&material) {
+            material.base_color = if result.is_ok() { GHOST_VALID_COLOR } else { GHOST_INVALID_COLOR };
+        }
+        for mut text in hint_q.iter_mut() {
+            text.0 = match result {
+                Err(error) => error.to_string(),
+                Ok(cost) if placements.len() > 1 => format!("{} x {} ({})", placements.len(), building_type.name(), cost),
+                Ok(_) => String::new(),
+            };
+        }
+    }
+}
+
+/// Spawn a building on a validated footprint and claim its tiles
+#[allow(clippy::too_many_arguments)]
+fn spawn_building(
+    commands: &mut Commands,
+    building_type: BuildingType,
+    placement: &Placement,
+    rotation: u8,
+    occupancy: &mut TileOccupancy,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    asset_server: &AssetServer,
+) {
+    let building = commands.spawn((
+        Mesh3d(building_type.mesh(meshes)),
+        MeshMaterial3d(building_type.material(building_type.color(), materials, asset_server)),
+        building_type.transform(placement, rotation),
+        Building { building_type, tile: placement.tile, rotation },
+        Health::new(building_type.max_health()),
+        NavObstacle { size: placement.world_size() },
+        MapEntity,
+    )).id();
+
+    for tile in placement.tiles() {
+        occupancy.tiles.insert(tile, building);
     }
 }
 
+/// Click places a building. Walls are dragged: press starts a line, release builds it.
+#[allow(clippy::too_many_arguments)]
 fn place_building(
     mut commands: Commands,
     mouse: Res<ButtonInput<MouseButton>>,
-    build_mode: Res<BuildMode>,
+    mut build_mode: ResMut<BuildMode>,
     windows: Query<&Window>,
     camera_q: Query<(&Camera, &GlobalTransform), With<crate::client::camera::MainCamera>>,
+    ui_q: Query<&Interaction>,
+    check: PlacementCheck,
+    mut occupancy: ResMut<TileOccupancy>,
     mut game_resources: ResMut<GameResources>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
 ) {
-    if !build_mode.active || !mouse.just_pressed(MouseButton::Left) {
+    if !build_mode.active || build_mode.demolish {
+        build_mode.drag_start = None;
         return;
     }
 
-    // Cost Check (Simple Wall cost: 20 Wood)
-    if game_resources.wood < 20 {
-        info!("Not enough wood!");
+    let pressed = mouse.just_pressed(MouseButton::Left);
+    let released = mouse.just_released(MouseButton::Left) && build_mode.drag_start.is_some();
+    if !pressed && !released {
+        return;
+    }
+    // Clicks on the menu bar are not placements
+    if pressed && ui_q.iter().any(|interaction| *interaction != Interaction::None) {
         return;
     }
 
     let (camera, camera_transform) = camera_q.single();
     let window = windows.single();
+    let Some(target_pos) = cursor_ground_position(window, camera, camera_transform) else {
+        build_mode.drag_start = None;
+        return;
+    };
+    let cursor_tile = tile_at(target_pos);
+    let building_type = build_mode.building_type;
 
-    if let Some(cursor_position) = window.cursor_position() {
-        if let Ok(ray) = camera.viewport_to_world(camera_transform, cursor_position) {
-             if ray.direction.y.abs() > f32::EPSILON {
-                let t = -ray.origin.y / ray.direction.y;
-                if t >= 0.0 {
-                    let target_pos = ray.origin + ray.direction * t;
-
-                    // Deduct resources
-                    game_resources.wood -= 20;
-
-                    // Place actual building
-                    let mesh = meshes.add(Cuboid::new(1.0, 2.0, 1.0));
-                    let material = materials.add(Color::srgb(0.5, 0.5, 0.5));
-
-                    commands.spawn((
-                        Mesh3d(mesh),
-                        MeshMaterial3d(material),
-                        Transform::from_xyz(target_pos.x, 1.0, target_pos.z),
-                        Building,
-                    ));
-                }
-            }
+    if pressed && building_type == BuildingType::Wall {
+        build_mode.drag_start = Some(cursor_tile);
+        return;
+    }
+
+    let placements = planned_placements(&build_mode, cursor_tile);
+    build_mode.drag_start = None;
+    let cost = match validate_placements(&placements, building_type, &check, &occupancy, &game_resources) {
+        Ok(cost) => cost,
+        Err(error) => {
+            info!("Can't build here: {}", error);
+            return;
+        }
+    };
+
+    // Deduct resources
+    cost.pay(&mut game_resources);
+
+    // Place actual buildings
+    for placement in &placements {
+        spawn_building(
+            &mut commands,
+            building_type,
+            placement,
+            build_mode.rotation,
+            &mut occupancy,
+            &mut meshes,
+            &mut materials,
+            &asset_server,
+        );
+    }
+}
+
+/// Building under the cursor, for the demolish tool
+fn hovered_building(
+    windows: &Query<&Window>,
+    camera_q: &Query<(&Camera, &GlobalTransform), With<crate::client::camera::MainCamera>>,
+    occupancy: &TileOccupancy,
+) -> Option<Entity> {
+    let (camera, camera_transform) = camera_q.get_single().ok()?;
+    let window = windows.get_single().ok()?;
+    let target_pos = cursor_ground_position(window, camera, camera_transform)?;
+    occupancy.get(tile_at(target_pos))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn demolish_building(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    build_mode: Res<BuildMode>,
+    windows: Query<&Window>,
+    camera_q: Query<(&Camera, &GlobalTransform), With<crate::client::camera::MainCamera>>,
+    ui_q: Query<&Interaction>,
+    occupancy: Res<TileOccupancy>,
+    building_q: Query<&Building>,
+    mut game_resources: ResMut<GameResources>,
+) {
+    if !build_mode.active || !build_mode.demolish || !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+    if ui_q.iter().any(|interaction| *interaction != Interaction::None) {
+        return;
+    }
+
+    if let Some(entity) = hovered_building(&windows, &camera_q, &occupancy)
+        && let Ok(building) = building_q.get(entity)
+    {
+        let refund = building.building_type.cost().refund();
+        refund.add_to(&mut game_resources);
+        info!("Demolished {} (+{})", building.building_type.name(), refund);
+        // Tiles are freed by release_tiles
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Outline the building the demolish tool would remove
+fn highlight_demolish_target(
+    mut gizmos: Gizmos,
+    build_mode: Res<BuildMode>,
+    windows: Query<&Window>,
+    camera_q: Query<(&Camera, &GlobalTransform), With<crate::client::camera::MainCamera>>,
+    occupancy: Res<TileOccupancy>,
+    building_q: Query<&Building>,
+    mut hint_q: Query<&mut Text, With<BuildHintText>>,
+) {
+    if !build_mode.active || !build_mode.demolish {
+        return;
+    }
+
+    let hovered = hovered_building(&windows, &camera_q, &occupancy)
+        .and_then(|entity| building_q.get(entity).ok());
+    for mut text in hint_q.iter_mut() {
+        text.0 = match hovered {
+            Some(building) => format!("Demolish {} (+{})", building.building_type.name(), building.building_type.cost().refund()),
+            None => "Demolish: click a building".to_string(),
+        };
+    }
+
+    if let Some(building) = hovered {
+        let placement = Placement {
+            tile: building.tile,
+            footprint: building.building_type.rotated_footprint(building.rotation),
+        };
+        let center = placement.center() + Vec3::Y * 0.05;
+        // Rects are drawn in the XY plane; lay them flat on the ground
+        gizmos.rect(
+            Isometry3d::new(center, Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+            placement.world_size(),
+            DEMOLISH_HIGHLIGHT_COLOR,
+        );
+    }
+}
+
+/// Darken buildings towards red as they take damage
+fn tint_damaged_buildings(
+    building_q: Query<(&Building, &Health, &MeshMaterial3d<StandardMaterial>), Changed<Health>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (building, health, material) in building_q.iter() {
+        if let Some(material) = materials.get_mut(&material.0) {
+            let damage = 1.0 - health.fraction();
+            material.base_color = building.building_type.color().mix(&DAMAGED_BUILDING_COLOR, damage);
         }
     }
 }
+
+fn destroy_buildings(
+    mut commands: Commands,
+    mut death_events: EventReader<DeathEvent>,
+    building_q: Query<&Building>,
+) {
+    for event in death_events.read() {
+        if let Ok(building) = building_q.get(event.entity) {
+            info!("{:?} destroyed!", building.building_type);
+            commands.entity(event.entity).despawn_recursive();
+        }
+    }
+}
+
+/// Free the tiles of destroyed buildings
+fn release_tiles(mut occupancy: ResMut<TileOccupancy>, mut removed: RemovedComponents<Building>) {
+    for entity in removed.read() {
+        occupancy.tiles.retain(|_, owner| *owner != entity);
+    }
+}
@@ -35,11 +35,26 @@ fn camera_follow_player(
     player_query: Query<&Transform, (With<crate::client::player::Player>, Without<MainCamera>)>,
     mut camera_query: Query<&mut Transform, With<MainCamera>>,
 ) {
-    if let Ok(player_transform) = player_query.get_single() {
-        if let Ok(mut camera_transform) = camera_query.get_single_mut() {
-            let offset = Vec3::new(10.0, 10.0, 10.0);
-            camera_transform.translation = player_transform.translation + offset;
-            camera_transform.look_at(player_transform.translation, Vec3::Y);
-        }
+    if let Ok(player_transform) = player_query.get_single()
+        && let Ok(mut camera_transform) = camera_query.get_single_mut()
+    {
+        let offset = Vec3::new(10.0, 10.0, 10.0);
+        camera_transform.translation = player_transform.translation + offset;
+        camera_transform.look_at(player_transform.translation, Vec3::Y);
     }
 }
+
+/// Raycast the cursor onto the ground plane (Y = 0)
+pub fn cursor_ground_position(
+    window: &Window,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+) -> Option<Vec3> {
+    let cursor_position = window.cursor_position()?;
+    let ray = camera.viewport_to_world(camera_transform, cursor_position).ok()?;
+    if ray.direction.y.abs() <= f32::EPSILON {
+        return None;
+    }
+    let t = -ray.origin.y / ray.direction.y;
+    (t >= 0.0).then(|| ray.origin + ray.direction * t)
+}
@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+use crate::client::animation::Facing;
+
+pub struct CombatPlugin;
+
+impl Plugin for CombatPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DamageEvent>();
+        app.add_event::<DeathEvent>();
+        app.add_systems(Update, (tick_attack_cooldowns, apply_damage).chain());
+    }
+}
+
+/// Half-angle of a melee swing, as a cosine (0.5 = 60° to either side of the facing direction)
+pub const MELEE_ARC_COS: f32 = 0.5;
+
+/// Hit points of anything that can be damaged
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Health {
+    pub current: u32,
+    pub max: u32,
+}
+
+impl Health {
+    pub fn new(max: u32) -> Self {
+        Self { current: max, max }
+    }
+
+    /// Returns true if this hit was the killing blow
+    pub fn take_damage(&mut self, amount: u32) -> bool {
+        if self.current == 0 {
+            return false;
+        }
+        self.current = self.current.saturating_sub(amount);
+        self.current == 0
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.current == 0
+    }
+
+    pub fn fraction(&self) -> f32 {
+        if self.max == 0 {
+            return 0.0;
+        }
+        self.current as f32 / self.max as f32
+    }
+}
+
+/// Close-range attack with a cooldown. Used by the player and by monsters.
+#[derive(Component, Debug, Clone)]
+pub struct MeleeAttack {
+    pub damage: u32,
+    /// Reach in world units, measured on the ground plane
+    pub range: f32,
+    pub cooldown: Timer,
+}
+
+impl MeleeAttack {
+    pub fn new(damage: u32, range: f32, cooldown_secs: f32) -> Self {
+        let mut cooldown = Timer::from_seconds(cooldown_secs, TimerMode::Once);
+        // Ready to swing right away
+        cooldown.tick(cooldown.duration());
+        Self { damage, range, cooldown }
+    }
+
+    pub fn ready(&self) -> bool {
+        self.cooldown.finished()
+    }
+
+    /// Start the cooldown. Returns false if the attack is not ready yet.
+    pub fn trigger(&mut self) -> bool {
+        if !self.ready() {
+            return false;
+        }
+        self.cooldown.reset();
+        true
+    }
+
+    /// Is `target` within reach of an attacker at `origin` looking towards `facing`?
+    pub fn reaches(&self, origin: Vec3, facing: Facing, target: Vec3) -> bool {
+        let offset = Vec3::new(target.x - origin.x, 0.0, target.z - origin.z);
+        let distance = offset.length();
+        if distance > self.range {
+            return false;
+        }
+        // Standing on top of the target always hits
+        distance < 0.01 || offset.normalize().dot(facing.direction()) >= MELEE_ARC_COS
+    }
+}
+
+/// Request to damage `target`. Anything with [`Health`] can receive it.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DamageEvent {
+    pub target: Entity,
+    pub amount: u32,
+    pub source: Option<Entity>,
+}
+
+/// Sent once when a [`Health`] reaches zero
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DeathEvent {
+    pub entity: Entity,
+    pub killer: Option<Entity>,
+}
+
+fn tick_attack_cooldowns(mut query: Query<&mut MeleeAttack>, time: Res<Time>) {
+    for mut attack in query.iter_mut() {
+        if !attack.ready() {
+            attack.cooldown.tick(time.delta());
+        }
+    }
+}
+
+fn apply_damage(
+    mut damage_events: EventReader<DamageEvent>,
+    mut death_events: EventWriter<DeathEvent>,
+    mut health_q: Query<&mut Health>,
+) {
+    for event in damage_events.read() {
+        let Ok(mut health) = health_q.get_mut(event.target) else {
+            continue;
+        };
+        if health.take_damage(event.amount) {
+            death_events.send(DeathEvent {
+                entity: event.target,
+                killer: event.source,
+            });
+        }
+    }
+}
@@ -1,93 +1,325 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use rand::rngs::SmallRng;
+use rand::Rng;
+use std::collections::HashMap;
+use crate::client::animation::{AnimationFinished, Facing, SpriteAnimator, SpriteManifest, StandardSpriteManifest};
+use crate::client::combat::{DamageEvent, DeathEvent, Health, MeleeAttack};
+use crate::client::graphics::{Animation, create_sprite_material, create_sprite_mesh};
+use crate::client::map::MapEntity;
+use crate::client::navigation::{NavGrid, NavObstacle};
+use crate::client::palette::PaletteSwap;
+use crate::shared::data::monsters::MonsterDef;
 
 pub struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(WaveManager {
-            timer: Timer::from_seconds(30.0, TimerMode::Repeating),
-            wave_count: 0,
-        });
-        app.add_systems(Update, (spawn_waves, enemy_chase_player));
+        app.init_resource::<MonsterAssets>();
+        app.add_systems(Update, (
+            enemy_chase_player,
+            start_monster_death,
+            despawn_dead_monsters,
+            spawn_zone_enemies,
+        ));
     }
 }
 
+/// Monsters stop this close to their target (ground distance)
+const ENEMY_STOP_DISTANCE: f32 = 1.2;
+const ENEMY_ATTACK_RANGE: f32 = 1.5;
+const ENEMY_ATTACK_COOLDOWN: f32 = 1.0;
+
 #[derive(Component)]
 pub struct Enemy;
 
-#[derive(Resource)]
-pub struct WaveManager {
-    pub timer: Timer,
-    pub wave_count: u32,
+/// Which roster entry this enemy was spawned from
+#[derive(Component)]
+pub struct Monster(pub &'static MonsterDef);
+
+/// Recoloured variant of a base monster (see `PaletteSwap`)
+#[derive(Component)]
+pub struct Elite;
+
+/// Playing the die clip; despawned when it finishes
+#[derive(Component)]
+pub struct Dying;
+
+/// Keeps up to `max_alive` monsters around the entity, replacing the dead one by one
+#[derive(Component, Debug, Clone)]
+pub struct EnemyZone {
+    /// Picked at random for each spawn
+    pub monsters: Vec<&'static MonsterDef>,
+    /// World units around the zone's position
+    pub radius: f32,
+    pub max_alive: u32,
+    pub respawn: Timer,
+    alive: Vec<Entity>,
+    populated: bool,
+    /// Picks monsters and spots; each zone has its own so zones don't shift each other's rolls
+    rng: SmallRng,
 }
 
-fn spawn_waves(
-    mut commands: Commands,
-    mut wave_manager: ResMut<WaveManager>,
-    time: Res<Time>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
-    wave_manager.timer.tick(time.delta());
-
-    if wave_manager.timer.finished() {
-        wave_manager.wave_count += 1;
-        info!("Wave {} Started!", wave_manager.wave_count);
-
-        let enemy_mesh = meshes.add(Cuboid::new(1.0, 1.0, 1.0));
-        let enemy_mat = materials.add(Color::srgb(0.8, 0.1, 0.1));
-
-        // Spawn enemies based on wave count
-        let count = 2 + wave_manager.wave_count * 2;
-
-        for i in 0..count {
-            // Random position at edge
-            let angle = (i as f32 / count as f32) * std::f32::consts::TAU;
-            let radius = 20.0;
-            let x = angle.cos() * radius;
-            let z = angle.sin() * radius;
-
-            commands.spawn((
-                Mesh3d(enemy_mesh.clone()),
-                MeshMaterial3d(enemy_mat.clone()),
-                Transform::from_xyz(x, 0.5, z),
-                Enemy,
-            ));
+impl EnemyZone {
+    /// `rng` should be the zone's own stream from [`MapSeed::rng`](crate::client::seed::MapSeed::rng)
+    pub fn new(monsters: Vec<&'static MonsterDef>, radius: f32, max_alive: u32, respawn_secs: f32, rng: SmallRng) -> Self {
+        Self {
+            monsters,
+            radius,
+            max_alive,
+            respawn: Timer::from_seconds(respawn_secs, TimerMode::Once),
+            alive: Vec::new(),
+            populated: false,
+            rng,
         }
     }
 }
 
+/// Mesh/material/manifest per monster id, shared by every instance
+#[derive(Resource, Default)]
+pub struct MonsterAssets {
+    visuals: HashMap<&'static str, MonsterVisual>,
+}
+
+#[derive(Clone)]
+struct MonsterVisual {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+    manifest: Handle<SpriteManifest>,
+}
+
+/// Spawns monsters from the `shared::data::monsters` roster
+#[derive(SystemParam)]
+pub struct MonsterSpawner<'w> {
+    asset_server: Res<'w, AssetServer>,
+    meshes: ResMut<'w, Assets<Mesh>>,
+    materials: ResMut<'w, Assets<StandardMaterial>>,
+    standard_manifest: Res<'w, StandardSpriteManifest>,
+    assets: ResMut<'w, MonsterAssets>,
+}
+
+impl MonsterSpawner<'_> {
+    pub fn manifest(&mut self, def: &'static MonsterDef) -> Handle<SpriteManifest> {
+        self.visual(def).manifest
+    }
+
+    fn visual(&mut self, def: &'static MonsterDef) -> MonsterVisual {
+        if let Some(visual) = self.assets.visuals.get(def.id) {
+            return visual.clone();
+        }
+
+        let texture = self.asset_server.load(def.sprite);
+        let visual = MonsterVisual {
+            mesh: create_sprite_mesh(&mut self.meshes, Vec2::splat(def.size.world_size())),
+            material: create_sprite_material(&mut self.materials, texture, AlphaMode::Blend),
+            manifest: match def.manifest {
+                Some(path) => self.asset_server.load(path),
+                None => self.standard_manifest.0.clone(),
+            },
+        };
+        self.assets.visuals.insert(def.id, visual.clone());
+        visual
+    }
+
+    /// Spawn a monster at `position` (on the ground), optionally recoloured with a manifest palette
+    pub fn spawn(
+        &mut self,
+        commands: &mut Commands,
+        def: &'static MonsterDef,
+        position: Vec3,
+        palette: Option<&str>,
+    ) -> Entity {
+        let visual = self.visual(def);
+        let size = def.size.world_size();
+
+        commands.spawn((
+            Transform::from_translation(position),
+            Visibility::default(),
+            Facing::default(),
+            Enemy,
+            Monster(def),
+            MapEntity,
+            Health::new(def.hp),
+            MeleeAttack::new(def.damage, ENEMY_ATTACK_RANGE, ENEMY_ATTACK_COOLDOWN),
+        ))
+        .with_children(|parent| {
+            let mut sprite = parent.spawn((
+                Mesh3d(visual.mesh),
+                MeshMaterial3d(visual.material),
+                Transform::from_xyz(0.0, size * 0.5, 0.0)
+                    .with_rotation(Quat::from_rotation_x(-45.0f32.to_radians())),
+                Animation::new(4, 4, 8.0),
+                SpriteAnimator::new(visual.manifest, "walk"),
+            ));
+            if let Some(palette) = palette {
+                sprite.insert(PaletteSwap::new(palette));
+            }
+        })
+        .id()
+    }
+}
+
+#[allow(clippy::type_complexity)]
 fn enemy_chase_player(
     // Targets: Player or Buildings
-    target_q: Query<&Transform, Or<(With<crate::client::player::Player>, With<crate::client::building::Building>)>>,
-    mut enemy_q: Query<&mut Transform, (With<Enemy>, Without<crate::client::player::Player>, Without<crate::client::building::Building>)>,
+    target_q: Query<(Entity, &Transform, Option<&Health>, Option<&NavObstacle>), Or<(With<crate::client::player::Player>, With<crate::client::building::Building>)>>,
+    mut enemy_q: Query<(Entity, &mut Transform, &Monster, &mut Facing, &mut MeleeAttack, &Children), (With<Enemy>, Without<Dying>, Without<crate::client::player::Player>, Without<crate::client::building::Building>)>,
+    mut animator_q: Query<&mut SpriteAnimator>,
+    mut damage_events: EventWriter<DamageEvent>,
+    nav_grid: Res<NavGrid>,
     time: Res<Time>,
 ) {
     // Optimization: Find nearest target for each enemy (simple O(N*M))
     // For small counts this is fine.
 
-    for mut enemy_transform in enemy_q.iter_mut() {
-        let mut nearest_target: Option<Vec3> = None;
+    for (entity, mut enemy_transform, monster, mut facing, mut attack, children) in enemy_q.iter_mut() {
+        let mut nearest_target: Option<(Entity, Vec3)> = None;
         let mut min_dist_sq = f32::MAX;
 
-        for target_transform in target_q.iter() {
-            let dist_sq = target_transform.translation.distance_squared(enemy_transform.translation);
+        for (target, target_transform, health, obstacle) in target_q.iter() {
+            if health.is_some_and(|health| health.is_dead()) {
+                continue;
+            }
+            // Buildings are approached up to the edge of their footprint
+            let mut target_pos = target_transform.translation;
+            if let Some(obstacle) = obstacle {
+                let half = obstacle.size * 0.5;
+                let offset = enemy_transform.translation - target_pos;
+                target_pos.x += offset.x.clamp(-half.x, half.x);
+                target_pos.z += offset.z.clamp(-half.y, half.y);
+            }
+            let dist_sq = target_pos.distance_squared(enemy_transform.translation);
             if dist_sq < min_dist_sq {
                 min_dist_sq = dist_sq;
-                nearest_target = Some(target_transform.translation);
+                nearest_target = Some((target, target_pos));
             }
         }
 
-        if let Some(target_pos) = nearest_target {
+        let mut swung = false;
+        if let Some((target, target_pos)) = nearest_target {
             let direction = target_pos - enemy_transform.translation;
-            let distance = direction.length();
+            let flat_direction = Vec3::new(direction.x, 0.0, direction.z);
+            let distance = flat_direction.length();
 
-            if distance > 1.2 {
-                let move_speed = 2.0;
-                let move_dist = move_speed * time.delta_secs();
-                enemy_transform.translation += direction.normalize() * move_dist;
-                enemy_transform.look_at(target_pos, Vec3::Y);
+            if distance > ENEMY_STOP_DISTANCE {
+                // Follow the flow field around walls and trees; straight line when off the grid
+                let heading = nav_grid
+                    .flow_direction(enemy_transform.translation)
+                    .unwrap_or(flat_direction.normalize());
+                let move_dist = monster.0.speed * time.delta_secs();
+                enemy_transform.translation += heading * move_dist;
+                // No look_at: rotating the container would spin sprite children with it
+                *facing = Facing::from_direction(heading);
+            } else {
+                if distance > 0.01 {
+                    *facing = Facing::from_direction(flat_direction);
+                }
+                if attack.reaches(enemy_transform.translation, *facing, target_pos) && attack.trigger() {
+                    damage_events.send(DamageEvent {
+                        target,
+                        amount: attack.damage,
+                        source: Some(entity),
+                    });
+                    swung = true;
+                }
             }
         }
+
+        for child in children.iter() {
+            if let Ok(mut animator) = animator_q.get_mut(*child) {
+                if swung {
+                    animator.restart("attack");
+                } else if animator.state == "attack" && animator.finished {
+                    animator.play("walk");
+                }
+            }
+        }
+    }
+}
+
+/// Health hit zero: play the die clip
+fn start_monster_death(
+    mut commands: Commands,
+    mut death_events: EventReader<DeathEvent>,
+    enemy_q: Query<&Children, (With<Enemy>, Without<Dying>)>,
+    mut animator_q: Query<&mut SpriteAnimator>,
+) {
+    for event in death_events.read() {
+        let entity = event.entity;
+        let Ok(children) = enemy_q.get(entity) else {
+            continue;
+        };
+
+        let mut has_animator = false;
+        for child in children.iter() {
+            if let Ok(mut animator) = animator_q.get_mut(*child) {
+                animator.restart("die");
+                has_animator = true;
+            }
+        }
+
+        if has_animator {
+            commands.entity(entity).insert(Dying);
+        } else {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn despawn_dead_monsters(
+    mut commands: Commands,
+    mut finished_events: EventReader<AnimationFinished>,
+    parent_q: Query<&Parent>,
+    dying_q: Query<(), With<Dying>>,
+) {
+    for event in finished_events.read() {
+        if event.state != "die" {
+            continue;
+        }
+        if let Ok(parent) = parent_q.get(event.entity)
+            && dying_q.contains(parent.get())
+        {
+            commands.entity(parent.get()).despawn_recursive();
+        }
+    }
+}
+
+/// Fill each zone right away, then bring monsters back one at a time
+fn spawn_zone_enemies(
+    mut commands: Commands,
+    mut zone_q: Query<(&Transform, &mut EnemyZone)>,
+    alive_q: Query<(), (With<Enemy>, Without<Dying>)>,
+    mut spawner: MonsterSpawner,
+    time: Res<Time>,
+) {
+    for (transform, mut zone) in zone_q.iter_mut() {
+        if zone.monsters.is_empty() {
+            continue;
+        }
+        zone.alive.retain(|entity| alive_q.contains(*entity));
+
+        let missing = zone.max_alive.saturating_sub(zone.alive.len() as u32);
+        let count = if !zone.populated {
+            zone.populated = true;
+            missing
+        } else if missing > 0 {
+            zone.respawn.tick(time.delta());
+            if !zone.respawn.finished() {
+                continue;
+            }
+            zone.respawn.reset();
+            1
+        } else {
+            continue;
+        };
+
+        let zone = &mut *zone;
+        for _ in 0..count {
+            let def = zone.monsters[zone.rng.gen_range(0..zone.monsters.len())];
+            let offset = Vec2::from_angle(zone.rng.gen_range(0.0..std::f32::consts::TAU)) * zone.rng.gen_range(0.0..=zone.radius);
+            let position = transform.translation + Vec3::new(offset.x, 0.0, offset.y);
+            let entity = spawner.spawn(&mut commands, def, position, None);
+            zone.alive.push(entity);
+        }
     }
 }
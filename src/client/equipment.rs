@@ -0,0 +1,224 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use crate::client::animation::{Facing, SpriteAnimator, SpriteManifest};
+use crate::client::assets::JsonAssetLoader;
+use crate::client::graphics::{Animation, create_sprite_material, create_sprite_mesh};
+pub use crate::shared::domain::inventory::PaperDollLayer;
+
+pub struct EquipmentPlugin;
+
+impl Plugin for EquipmentPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<AnchorSet>()
+            .register_asset_loader(JsonAssetLoader::<AnchorSet>::default())
+            .add_systems(Update, (spawn_paper_doll_layers, sync_paper_doll_layers).chain());
+    }
+}
+
+/// Distance between two paper-doll layers along the sprite normal
+const LAYER_Z_STEP: f32 = 0.01;
+
+/// Per-frame anchor points (`sprites/anchors/*.json`)
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+pub struct AnchorSet {
+    pub character_id: String,
+    pub frame_width: f32,
+    pub frame_height: f32,
+    /// Keyed by "{state}_{direction}", e.g. "walk_down"
+    pub animations: HashMap<String, AnchorClip>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AnchorClip {
+    pub state: String,
+    pub direction: String,
+    pub frames: Vec<AnchorFrame>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AnchorFrame {
+    pub anchors: HashMap<String, AnchorPoint>,
+    #[serde(default)]
+    pub rotation: f32,
+    #[serde(default)]
+    pub z_offset: i32,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct AnchorPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl AnchorSet {
+    /// Anchor frame for a state/direction, falling back to the "down" clip
+    pub fn frame(&self, state: &str, direction: &str, frame: usize) -> Option<&AnchorFrame> {
+        let clip = self
+            .animations
+            .get(&format!("{state}_{direction}"))
+            .or_else(|| self.animations.get(&format!("{state}_down")))?;
+        clip.frames.get(frame % clip.frames.len().max(1))
+    }
+
+    /// Rest pose the equipment sheets are drawn against (first idle frame)
+    pub fn rest(&self) -> Option<&AnchorFrame> {
+        self.frame("idle", "down", 0)
+    }
+
+    /// World offset of the layer following `key`, relative to the rest pose.
+    /// Mirrored frames flip both anchors, which negates the X delta.
+    pub fn layer_offset(&self, key: &str, frame: &AnchorFrame, mirrored: bool, size: Vec2) -> Option<Vec2> {
+        let from = self.rest()?.anchors.get(key)?;
+        let to = frame.anchors.get(key)?;
+        let dx = if mirrored { from.x - to.x } else { to.x - from.x };
+        // Anchor pixels are top-left based; world Y points up
+        Some(Vec2::new(
+            dx * size.x / self.frame_width,
+            -(to.y - from.y) * size.y / self.frame_height,
+        ))
+    }
+}
+
+/// Layered character. Lives on the entity that owns the body [`Animation`]/[`SpriteAnimator`];
+/// one child quad is spawned per layer and kept on the same frame.
+#[derive(Component)]
+pub struct PaperDoll {
+    pub gender: &'static str,
+    pub anchors: Handle<AnchorSet>,
+    /// Quad size of every layer in world units
+    pub size: Vec2,
+    /// Item file stem per slot, e.g. Weapon -> "iron_sword"
+    pub equipment: BTreeMap<PaperDollLayer, String>,
+}
+
+impl PaperDoll {
+    pub fn new(gender: &'static str, anchors: Handle<AnchorSet>, size: Vec2) -> Self {
+        Self {
+            gender,
+            anchors,
+            size,
+            equipment: BTreeMap::new(),
+        }
+    }
+
+    pub fn with(mut self, layer: PaperDollLayer, item: &str) -> Self {
+        self.equip(layer, item);
+        self
+    }
+
+    pub fn equip(&mut self, layer: PaperDollLayer, item: &str) {
+        if layer.asset_dir().is_some() {
+            self.equipment.insert(layer, item.to_string());
+        } else {
+            warn!("{:?} is not an equipment slot", layer);
+        }
+    }
+
+    pub fn unequip(&mut self, layer: PaperDollLayer) {
+        self.equipment.remove(&layer);
+    }
+
+    /// Texture path per layer, body first
+    pub fn layer_textures(&self) -> Vec<(PaperDollLayer, String)> {
+        let mut layers = vec![(
+            PaperDollLayer::Body,
+            format!("characters/base/{}/body.png", self.gender),
+        )];
+        for (layer, item) in &self.equipment {
+            if let Some(dir) = layer.asset_dir() {
+                layers.push((*layer, format!("{dir}/{item}.png")));
+            }
+        }
+        layers
+    }
+}
+
+/// One rendered layer of a [`PaperDoll`]
+#[derive(Component)]
+pub struct PaperDollSprite {
+    pub layer: PaperDollLayer,
+}
+
+/// (Re)spawn layer quads whenever the doll's equipment changes
+fn spawn_paper_doll_layers(
+    mut commands: Commands,
+    doll_q: Query<(Entity, &PaperDoll, Option<&Children>), Changed<PaperDoll>>,
+    layer_q: Query<(), With<PaperDollSprite>>,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, doll, children) in doll_q.iter() {
+        if let Some(children) = children {
+            for child in children.iter() {
+                if layer_q.contains(*child) {
+                    commands.entity(*child).despawn_recursive();
+                }
+            }
+        }
+
+        // Frames are picked per material, so all layers can share one quad
+        let mesh = create_sprite_mesh(&mut meshes, doll.size);
+
+        commands.entity(entity).with_children(|parent| {
+            for (layer, path) in doll.layer_textures() {
+                let material = create_sprite_material(&mut materials, asset_server.load(path), AlphaMode::Blend);
+                parent.spawn((
+                    Mesh3d(mesh.clone()),
+                    MeshMaterial3d(material),
+                    Transform::from_xyz(0.0, 0.0, layer_z(layer)),
+                    Animation::new(4, 4, 8.0),
+                    PaperDollSprite { layer },
+                ));
+            }
+        });
+    }
+}
+
+/// Copy the body frame to every layer and offset layers by the per-frame anchors
+fn sync_paper_doll_layers(
+    doll_q: Query<(&PaperDoll, &Animation, &SpriteAnimator, &Children, Option<&Parent>)>,
+    facing_q: Query<&Facing>,
+    mut layer_q: Query<(&PaperDollSprite, &mut Animation, &mut Transform), Without<PaperDoll>>,
+    anchor_sets: Res<Assets<AnchorSet>>,
+    manifests: Res<Assets<SpriteManifest>>,
+) {
+    for (doll, body, animator, children, parent) in doll_q.iter() {
+        let facing = parent
+            .and_then(|parent| facing_q.get(parent.get()).ok())
+            .copied()
+            .unwrap_or_default();
+        // Mirrored directions reuse the source direction's anchors with X flipped
+        let mirror_source = manifests
+            .get(&animator.manifest)
+            .and_then(|manifest| manifest.mirror.source_for(facing.name()));
+        let direction = mirror_source.unwrap_or(facing.name());
+        let anchors = anchor_sets.get(&doll.anchors);
+        let frame = anchors.and_then(|set| set.frame(&animator.state, direction, animator.frame));
+
+        for child in children.iter() {
+            let Ok((sprite, mut animation, mut transform)) = layer_q.get_mut(*child) else {
+                continue;
+            };
+
+            Animation::set_sprite_frame(&mut animation, body.sprite_frame());
+
+            let offset = match (anchors, frame, sprite.layer.anchor()) {
+                (Some(set), Some(frame), Some(key)) => set
+                    .layer_offset(key, frame, mirror_source.is_some(), doll.size)
+                    .unwrap_or(Vec2::ZERO),
+                _ => Vec2::ZERO,
+            };
+            let translation = Vec3::new(offset.x, offset.y, layer_z(sprite.layer));
+            if transform.translation != translation {
+                transform.translation = translation;
+            }
+        }
+    }
+}
+
+fn layer_z(layer: PaperDollLayer) -> f32 {
+    // Body is the reference plane; shadow ends up just behind it
+    (layer.z_index() as f32 - PaperDollLayer::Body.z_index() as f32) * LAYER_Z_STEP
+}
@@ -1,4 +1,7 @@
 use bevy::prelude::*;
+use crate::client::combat::Health;
+use crate::client::player::Player;
+use crate::client::wave::WaveManager;
 
 pub struct GamePlugin;
 
@@ -42,9 +45,20 @@ fn setup_ui(mut commands: Commands) {
 
 fn update_ui(
     resources: Res<GameResources>,
+    player_q: Query<&Health, With<Player>>,
+    wave_manager: Option<Res<WaveManager>>,
     mut query: Query<&mut Text, With<ResourceText>>,
 ) {
     for mut text in &mut query {
         text.0 = format!("Wood: {} | Gold: {}", resources.wood, resources.gold);
+        if let Ok(health) = player_q.get_single() {
+            text.0 += &format!(" | HP: {}/{}", health.current, health.max);
+        }
+        if let Some(wave_manager) = &wave_manager {
+            text.0 += &format!(" | Wave: {}", wave_manager.wave_count);
+            if let Some(seconds) = wave_manager.next_wave_in() {
+                text.0 += &format!(" (next in {}s)", seconds.ceil() as u32);
+            }
+        }
     }
 }
@@ -1,15 +1,20 @@
+use bevy::math::Affine2;
 use bevy::prelude::*;
-
+use std::collections::HashMap;
 
 pub struct GraphicsPlugin;
 
 impl Plugin for GraphicsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, animate_sprites);
+        app.init_resource::<SpriteFrameMaterials>();
+        app.add_systems(
+            PostUpdate,
+            (track_sprite_materials, animate_sprites, apply_sprite_frames, evict_sprite_frame_materials).chain(),
+        );
     }
 }
 
-/// Component to manage sprite animation state for 3D meshes (manual UV shifting)
+/// Component to manage sprite animation state for 3D meshes (material UV transform)
 #[derive(Component)]
 pub struct Animation {
     pub texture_atlas_rows: usize,
@@ -18,6 +23,34 @@ pub struct Animation {
     pub current_col: usize,
     pub timer: Timer,
     pub is_playing: bool,
+    /// Mirror the frame horizontally (manifest `mirror` rules)
+    pub flip_x: bool,
+}
+
+/// Which cell of the sheet an [`Animation`] shows
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SpriteFrame {
+    pub rows: usize,
+    pub cols: usize,
+    pub row: usize,
+    pub col: usize,
+    pub flip_x: bool,
+}
+
+impl SpriteFrame {
+    /// Material UV transform that maps the quad onto this cell
+    pub fn uv_transform(&self) -> Affine2 {
+        // Rectangle UVs run (0,0) top-left to (1,1) bottom-right, same as the sheet rows
+        let cols = self.cols.max(1) as f32;
+        let rows = self.rows.max(1) as f32;
+        let mut scale = Vec2::new(1.0 / cols, 1.0 / rows);
+        let mut offset = Vec2::new(self.col as f32 / cols, self.row as f32 / rows);
+        if self.flip_x {
+            scale.x = -scale.x;
+            offset.x += 1.0 / cols;
+        }
+        Affine2::from_scale_angle_translation(scale, 0.0, offset)
+    }
 }
 
 impl Animation {
@@ -29,6 +62,33 @@ impl Animation {
             current_col: 0,
             timer: Timer::from_seconds(1.0 / fps, TimerMode::Repeating),
             is_playing: true,
+            flip_x: false,
+        }
+    }
+
+    pub fn sprite_frame(&self) -> SpriteFrame {
+        SpriteFrame {
+            rows: self.texture_atlas_rows,
+            cols: self.texture_atlas_cols,
+            row: self.current_row,
+            col: self.current_col,
+            flip_x: self.flip_x,
+        }
+    }
+
+    /// Controllers call this every frame; it only marks the component changed
+    /// (and so swaps the material) when the frame actually differs.
+    pub fn set_sprite_frame(this: &mut Mut<Self>, frame: SpriteFrame) {
+        if this.sprite_frame() != frame {
+            this.texture_atlas_rows = frame.rows;
+            this.texture_atlas_cols = frame.cols;
+            this.current_row = frame.row;
+            this.current_col = frame.col;
+            this.flip_x = frame.flip_x;
+        }
+        if this.is_playing {
+            // Externally driven: stop the built-in column looper
+            this.is_playing = false;
         }
     }
 }
@@ -56,64 +116,116 @@ pub fn create_sprite_material(
     })
 }
 
+/// Shared material an animated sprite was spawned (or palette-swapped) with, plus the
+/// frame material currently on its mesh
+#[derive(Component)]
+pub struct SpriteMaterial {
+    pub source: Handle<StandardMaterial>,
+    frame: AssetId<StandardMaterial>,
+}
+
+/// One material per (source material, frame). Sprites showing the same frame share it,
+/// so they still batch while animating independently. Entries go away with their source
+/// material, once the last sprite using it is gone.
+#[derive(Resource, Default)]
+pub struct SpriteFrameMaterials(HashMap<(AssetId<StandardMaterial>, SpriteFrame), Handle<StandardMaterial>>);
+
+impl SpriteFrameMaterials {
+    /// Cached frame materials
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Remember the source material whenever the handle is replaced from outside
+/// (spawn, palette swap), so frame materials are derived from it
+#[allow(clippy::type_complexity)]
+fn track_sprite_materials(
+    mut commands: Commands,
+    mut query: Query<
+        (Entity, &MeshMaterial3d<StandardMaterial>, &mut Animation, Option<&mut SpriteMaterial>),
+        Changed<MeshMaterial3d<StandardMaterial>>,
+    >,
+) {
+    for (entity, material_handle, mut animation, sprite_material) in query.iter_mut() {
+        match sprite_material {
+            // Our own frame swap
+            Some(sprite_material) if sprite_material.frame == material_handle.id() => continue,
+            Some(mut sprite_material) => sprite_material.source = material_handle.0.clone(),
+            None => {
+                commands.entity(entity).insert(SpriteMaterial {
+                    source: material_handle.0.clone(),
+                    frame: material_handle.id(),
+                });
+            }
+        }
+        // Pick the frame material for the new source
+        animation.set_changed();
+    }
+}
+
+/// Advance frames of self-playing animations
 fn animate_sprites(
     time: Res<Time>,
-    mut query: Query<(&mut Animation, &Mesh3d)>,
-    mut meshes: ResMut<Assets<Mesh>>,
+    mut query: Query<&mut Animation>,
 ) {
-    for (mut animation, mesh_handle) in query.iter_mut() {
+    for mut animation in query.iter_mut() {
         if animation.is_playing {
-            animation.timer.tick(time.delta());
+            // Ticking alone must not count as a change, only a new frame does
+            animation.bypass_change_detection().timer.tick(time.delta());
             if animation.timer.just_finished() {
-                // Logic to advance frame is handled by the controller (e.g. player controller changes col/row)
-                // But for looping animations (like walking), we might want to advance col here if configured.
-                // For now, let's assume the controller sets the specific Row/Col based on state,
-                // OR we implement a simple frame advancer here.
-
                 // Simple looper for columns (frames)
                 animation.current_col = (animation.current_col + 1) % animation.texture_atlas_cols;
             }
         }
+    }
+}
+
+/// Show the current frame by switching to the shared material for it.
+/// Only entities whose frame changed are touched; meshes and source materials are never modified.
+fn apply_sprite_frames(
+    mut query: Query<(&Animation, &mut SpriteMaterial, &mut MeshMaterial3d<StandardMaterial>), Changed<Animation>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut frame_materials: ResMut<SpriteFrameMaterials>,
+) {
+    for (animation, mut sprite_material, mut material_handle) in query.iter_mut() {
+        let frame = animation.sprite_frame();
+        let key = (sprite_material.source.id(), frame);
+        let handle = match frame_materials.0.get(&key) {
+            Some(handle) => handle.clone(),
+            None => {
+                let Some(source) = materials.get(&sprite_material.source).cloned() else {
+                    continue;
+                };
+                let handle = materials.add(StandardMaterial {
+                    uv_transform: frame.uv_transform(),
+                    ..source
+                });
+                frame_materials.0.insert(key, handle.clone());
+                handle
+            }
+        };
 
-        // Update UVs
-        if let Some(mesh) = meshes.get_mut(mesh_handle) {
-            let rows = animation.texture_atlas_rows as f32;
-            let cols = animation.texture_atlas_cols as f32;
-            let row = animation.current_row as f32;
-            let col = animation.current_col as f32;
-
-            // UV coordinates for the current frame
-            // Assuming texture starts top-left? Bevy mesh UVs are usually bottom-left (0,0) to top-right (1,1).
-            // But images are usually read top-to-bottom.
-            // Let's standard UV mapping: (0,0) is bottom-left.
-            // If the sprite sheet is standard: Row 0 is usually top.
-            // We might need to invert the Row index.
-
-            // Standard Grid:
-            // U_min = col / cols
-            // U_max = (col + 1) / cols
-            // V_min = (rows - 1 - row) / rows  <-- Flip Y
-            // V_max = (rows - row) / rows
-
-            let u_min = col / cols;
-            let u_max = (col + 1.0) / cols;
-            let v_min = (rows - 1.0 - row) / rows;
-            let v_max = (rows - row) / rows;
-
-            let uvs = vec![
-                [u_min, v_max], // Top-Left (Vertex 0 in Quad)
-                [u_max, v_max], // Top-Right (Vertex 1)
-                [u_max, v_min], // Bottom-Right (Vertex 2)
-                [u_min, v_min], // Bottom-Left (Vertex 3)
-            ];
-
-            // Note: Bevy's Rectangle mesh vertices order might differ.
-            // Rectangle::new creates a quad on the XY plane.
-            // Vertices: [(-w, h, 0), (w, h, 0), (w, -h, 0), (-w, -h, 0)] (roughly TL, TR, BR, BL)
-            // UVs default: [(0,0), (1,0), (1,1), (0,1)] - Wait, let's verify standard quad UVs.
-            // Actually, for a billboard we usually use a custom mesh or update the attribute.
-
-            mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        sprite_material.frame = handle.id();
+        if material_handle.id() != handle.id() {
+            material_handle.0 = handle;
+        }
+    }
+}
+
+/// Drop the frame materials of source materials nothing uses any more (despawned sprites,
+/// old maps, replaced palette variants), so the cache doesn't keep them alive forever
+fn evict_sprite_frame_materials(
+    mut events: EventReader<AssetEvent<StandardMaterial>>,
+    mut frame_materials: ResMut<SpriteFrameMaterials>,
+) {
+    for event in events.read() {
+        if let AssetEvent::Removed { id } = event {
+            frame_materials.0.retain(|(source, _), _| source != id);
         }
     }
 }
@@ -1,41 +1,390 @@
+use bevy::asset::LoadState;
 use bevy::prelude::*;
+use bevy::math::Affine2;
 use rand::Rng;
+use std::ops::RangeInclusive;
+use crate::client::assets::JsonAssetLoader;
+use crate::client::building::TileOccupancy;
+use crate::client::enemy::{Enemy, EnemyZone};
 use crate::client::graphics::{create_sprite_mesh, create_sprite_material};
+use crate::client::navigation::NavObstacle;
+use crate::client::player::Player;
+use crate::client::seed::MapSeed;
+use crate::client::tilemap::{GroundTiles, spawn_tile_layers};
+use crate::shared::constants::TILE_WORLD_SIZE;
+use crate::shared::data::monsters::monster_by_id;
+use crate::shared::map_file::MapFile;
+pub use crate::shared::map_file::ResourceType;
+use crate::shared::tilemap::{TileMap, tile_center_to_world, tile_to_world, world_to_tile};
 
 pub struct MapPlugin;
 
 impl Plugin for MapPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, spawn_map);
+        app.init_asset::<MapAsset>()
+            .register_asset_loader(JsonAssetLoader::<MapAsset>::default())
+            .init_resource::<ResourceSpawnConfig>()
+            .add_systems(Startup, (setup_lighting, load_active_map))
+            .add_systems(Update, (spawn_active_map, deplete_resource_nodes, regrow_resource_nodes).chain());
     }
 }
 
+/// Map loaded at startup (the DB default for `characters.current_map`)
+pub const DEFAULT_MAP: &str = "village_milles";
+
+/// Used for portals that don't name their own sprite
+const PORTAL_SPRITE: &str = "decorations/portal.png";
+
+/// Tile under a world position (may be outside the map)
+pub fn tile_at(position: Vec3) -> IVec2 {
+    world_to_tile(position.x, position.z).into()
+}
+
+/// World position (on the ground) of the -X/-Z corner of a tile
+pub fn tile_corner(tile: IVec2) -> Vec3 {
+    let (x, z) = tile_to_world(tile.x, tile.y);
+    Vec3::new(x, 0.0, z)
+}
+
+/// World position (on the ground) of the centre of a tile
+pub fn tile_center(tile: IVec2) -> Vec3 {
+    let (x, z) = tile_center_to_world(tile.x, tile.y);
+    Vec3::new(x, 0.0, z)
+}
+
+/// A `maps/*.json` file
+#[derive(Asset, TypePath, serde::Deserialize, Debug, Clone, Deref)]
+#[serde(transparent)]
+pub struct MapAsset(pub MapFile);
+
+/// The map the player is on. Spawned as soon as its file has loaded.
+#[derive(Resource, Debug)]
+pub struct ActiveMap {
+    pub id: String,
+    pub handle: Handle<MapAsset>,
+    /// Where the player appears; the map's own `player_spawn` if None
+    pub arrival: Option<IVec2>,
+    status: MapStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MapStatus {
+    Loading,
+    Spawned,
+    /// The file didn't load or didn't validate; nothing was spawned
+    Failed,
+}
+
+impl ActiveMap {
+    pub fn load(id: &str, asset_server: &AssetServer) -> Self {
+        Self {
+            id: id.to_string(),
+            handle: asset_server.load(format!("maps/{id}.json")),
+            arrival: None,
+            status: MapStatus::Loading,
+        }
+    }
+
+    /// Load a map with the player arriving on `tile` (through a portal)
+    pub fn load_at(id: &str, tile: IVec2, asset_server: &AssetServer) -> Self {
+        Self {
+            arrival: Some(tile),
+            ..Self::load(id, asset_server)
+        }
+    }
+
+    pub fn is_spawned(&self) -> bool {
+        self.status == MapStatus::Spawned
+    }
+
+    /// The map can't be entered; the portal transition sends the player back
+    pub fn has_failed(&self) -> bool {
+        self.status == MapStatus::Failed
+    }
+}
+
+/// Belongs to the current map; despawned when the player leaves it
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct MapEntity;
+
+/// Scenery sprite from the map file; blocking ones also stop building
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Decoration {
+    /// Ground footprint in world units, centred on the entity. Zero if it doesn't block.
+    pub footprint: Vec2,
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct Npc {
+    pub id: String,
+    pub name: String,
+}
+
+/// Leads to another map
+#[derive(Component, Debug, Clone)]
+pub struct Portal {
+    pub target_map: String,
+    pub target_tile: IVec2,
+}
+
 #[derive(Component)]
 pub struct ResourceNode {
     pub resource_type: ResourceType,
     pub amount: u32,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
-pub enum ResourceType {
-    Wood,
-    Gold,
+/// How many nodes of one resource type the map gets and how they come back
+#[derive(Debug, Clone)]
+pub struct ResourceSpawnRule {
+    pub resource_type: ResourceType,
+    /// Number of clusters scattered over the map
+    pub clusters: u32,
+    /// Nodes per cluster (fewer if there is no room)
+    pub cluster_size: RangeInclusive<u32>,
+    /// Nodes stay within this distance of their cluster centre
+    pub cluster_radius: f32,
+    /// Minimum distance between any two nodes
+    pub min_spacing: f32,
+    /// Resources in a fresh node
+    pub amount: u32,
+    /// Seconds before an emptied node grows back
+    pub regrow_secs: f32,
+}
+
+/// Resource densities per type, used on maps with `scatter_resources`.
+/// Insert a custom one before startup to change them.
+#[derive(Resource, Debug, Clone)]
+pub struct ResourceSpawnConfig {
+    pub rules: Vec<ResourceSpawnRule>,
 }
 
-fn spawn_map(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    asset_server: Res<AssetServer>,
+impl ResourceSpawnConfig {
+    pub fn rule(&self, resource_type: ResourceType) -> Option<&ResourceSpawnRule> {
+        self.rules.iter().find(|rule| rule.resource_type == resource_type)
+    }
+}
+
+impl Default for ResourceSpawnConfig {
+    fn default() -> Self {
+        Self {
+            rules: vec![
+                ResourceSpawnRule {
+                    resource_type: ResourceType::Wood,
+                    clusters: 6,
+                    cluster_size: 3..=6,
+                    cluster_radius: 4.0,
+                    min_spacing: 2.0,
+                    amount: 100,
+                    regrow_secs: 60.0,
+                },
+                ResourceSpawnRule {
+                    resource_type: ResourceType::Gold,
+                    clusters: 3,
+                    cluster_size: 2..=4,
+                    cluster_radius: 2.5,
+                    min_spacing: 1.5,
+                    amount: 150,
+                    regrow_secs: 120.0,
+                },
+            ],
+        }
+    }
+}
+
+/// Emptied node waiting to grow back. It keeps its entity but is hidden and walkable.
+#[derive(Component, Debug, Clone)]
+pub struct Regrowing {
+    pub resource_type: ResourceType,
+    pub timer: Timer,
+}
+
+/// Half size of the square around the player spawn kept free of scattered nodes
+const SPAWN_CLEAR_HALF_SIZE: f32 = 5.0;
+
+/// Nodes keep this far away from the map edge
+const MAP_EDGE_MARGIN: f32 = 2.0;
+
+/// Attempts at finding a free spot before giving up on a cluster / node
+const PLACEMENT_ATTEMPTS: u32 = 20;
+
+/// Units closer than this block a node from growing back
+const REGROW_CLEARANCE: f32 = 1.0;
+
+/// Seconds to wait before trying again when something stands on a regrowing node
+const REGROW_RETRY_SECS: f32 = 2.0;
+
+fn resource_spot_allowed(position: Vec2, tiles: &TileMap, spawn: Vec2, taken: &[Vec2], min_spacing: f32) -> bool {
+    let (width, height) = tiles.world_size();
+    let (x, z) = world_to_tile(position.x, position.y);
+    let offset = (position - spawn).abs();
+    position.x >= MAP_EDGE_MARGIN
+        && position.y >= MAP_EDGE_MARGIN
+        && position.x <= width - MAP_EDGE_MARGIN
+        && position.y <= height - MAP_EDGE_MARGIN
+        && offset.max_element() >= SPAWN_CLEAR_HALF_SIZE
+        && tiles.is_walkable(x, z)
+        && taken.iter().all(|other| other.distance(position) >= min_spacing)
+}
+
+/// Ground positions for one rule's nodes, grouped in clusters. `taken` holds
+/// every node placed so far (of any type) and is extended with the new ones.
+fn scatter_rule_nodes(
+    rule: &ResourceSpawnRule,
+    tiles: &TileMap,
+    spawn: Vec2,
+    taken: &mut Vec<Vec2>,
+    rng: &mut impl Rng,
+) -> Vec<Vec2> {
+    let (width, height) = tiles.world_size();
+    let mut positions = Vec::new();
+
+    for _ in 0..rule.clusters {
+        let Some(center) = (0..PLACEMENT_ATTEMPTS)
+            .map(|_| Vec2::new(rng.gen_range(0.0..width), rng.gen_range(0.0..height)))
+            .find(|center| resource_spot_allowed(*center, tiles, spawn, taken, rule.min_spacing))
+        else {
+            continue;
+        };
+
+        let count = rng.gen_range(rule.cluster_size.clone());
+        let mut placed = 0;
+        let mut candidate = center;
+        for _ in 0..count * PLACEMENT_ATTEMPTS {
+            if placed == count {
+                break;
+            }
+            if resource_spot_allowed(candidate, tiles, spawn, taken, rule.min_spacing) {
+                taken.push(candidate);
+                positions.push(candidate);
+                placed += 1;
+            }
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            let distance = rng.gen_range(0.0..=rule.cluster_radius);
+            candidate = center + Vec2::from_angle(angle) * distance;
+        }
+    }
+    positions
+}
+
+/// Nodes scattered over a map with `scatter_resources`, clear of its fixed nodes and decorations.
+/// Same map, tiles and rng state always give the same nodes.
+pub fn scatter_resource_nodes(
+    map: &MapFile,
+    tiles: &TileMap,
+    config: &ResourceSpawnConfig,
+    rng: &mut impl Rng,
+) -> Vec<(ResourceType, Vec2)> {
+    let spawn = tile_center(map.player_spawn.into());
+    let mut taken: Vec<Vec2> = map
+        .resources
+        .iter()
+        .map(|node| tile_center(node.tile.into()))
+        .chain(map.decorations.iter().map(|decoration| tile_center(decoration.tile.into())))
+        .map(|position| Vec2::new(position.x, position.z))
+        .collect();
+    let mut nodes = Vec::new();
+    for rule in &config.rules {
+        for position in scatter_rule_nodes(rule, tiles, Vec2::new(spawn.x, spawn.z), &mut taken, rng) {
+            nodes.push((rule.resource_type, position));
+        }
+    }
+    nodes
+}
+
+fn spawn_resource_node(
+    commands: &mut Commands,
+    assets: &mut MapSpawnAssets,
+    resource_type: ResourceType,
+    position: Vec2,
+    amount: u32,
 ) {
-    // Ground Plane
+    let size = resource_type.sprite_size();
+    let (mesh, material) = assets.sprite(resource_type.sprite(), Vec2::splat(size));
     commands.spawn((
-        Mesh3d(meshes.add(Plane3d::default().mesh().size(50.0, 50.0))),
-        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
-        Transform::from_xyz(0.0, 0.0, 0.0),
+        Mesh3d(mesh),
+        MeshMaterial3d(material),
+        // Sprite bottom on the ground, tilted back like the other billboards
+        Transform::from_xyz(position.x, size * 0.5, position.y).with_rotation(billboard_tilt()),
+        ResourceNode { resource_type, amount },
+        // Only the trunk / base blocks movement
+        NavObstacle { size: Vec2::ONE },
+        MapEntity,
     ));
+}
 
-    // Light
+/// Emptied nodes disappear and start growing back
+fn deplete_resource_nodes(
+    mut commands: Commands,
+    node_q: Query<(Entity, &ResourceNode), Changed<ResourceNode>>,
+    config: Res<ResourceSpawnConfig>,
+) {
+    for (entity, node) in node_q.iter() {
+        if node.amount > 0 {
+            continue;
+        }
+        let Some(rule) = config.rule(node.resource_type) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+        commands
+            .entity(entity)
+            .remove::<(ResourceNode, NavObstacle)>()
+            .insert((
+                Visibility::Hidden,
+                Regrowing {
+                    resource_type: node.resource_type,
+                    timer: Timer::from_seconds(rule.regrow_secs, TimerMode::Once),
+                },
+            ));
+    }
+}
+
+/// Bring nodes back once their timer runs out and nobody stands on them.
+/// A building placed on the spot in the meantime removes the node for good.
+#[allow(clippy::type_complexity)]
+fn regrow_resource_nodes(
+    mut commands: Commands,
+    mut regrowing_q: Query<(Entity, &Transform, &mut Regrowing)>,
+    unit_q: Query<&Transform, (Or<(With<Player>, With<Enemy>)>, Without<Regrowing>)>,
+    occupancy: Res<TileOccupancy>,
+    config: Res<ResourceSpawnConfig>,
+    time: Res<Time>,
+) {
+    for (entity, transform, mut regrowing) in regrowing_q.iter_mut() {
+        regrowing.timer.tick(time.delta());
+        if !regrowing.timer.finished() {
+            continue;
+        }
+
+        let position = transform.translation;
+        if !occupancy.is_free(tile_at(position)) {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        let blocked = unit_q.iter().any(|unit| {
+            Vec2::new(unit.translation.x - position.x, unit.translation.z - position.z).length() < REGROW_CLEARANCE
+        });
+        if blocked {
+            regrowing.timer = Timer::from_seconds(REGROW_RETRY_SECS, TimerMode::Once);
+            continue;
+        }
+
+        let amount = config.rule(regrowing.resource_type).map_or(0, |rule| rule.amount);
+        commands
+            .entity(entity)
+            .remove::<Regrowing>()
+            .insert((
+                Visibility::Inherited,
+                ResourceNode {
+                    resource_type: regrowing.resource_type,
+                    amount,
+                },
+                NavObstacle { size: Vec2::ONE },
+            ));
+    }
+}
+
+fn setup_lighting(mut commands: Commands) {
     commands.spawn((
         DirectionalLight {
             shadows_enabled: true,
@@ -44,39 +393,181 @@ fn spawn_map(
         Transform::from_xyz(5.0, 10.0, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
     ));
 
-    // Ambient Light
     commands.insert_resource(AmbientLight {
         color: Color::WHITE,
         brightness: 200.0,
     });
+}
 
-    // Trees
-    let tree_texture = asset_server.load("decorations/tree.png"); // Verified path
-    // Assuming tree sprite is roughly 2x3 meters or similar?
-    // Just usage a reasonable size. If the png is square, 2x2. If tall, maybe 2x3.
-    // Let's assume square or auto-fit. Let's use 3.0x3.0 for now.
-    let tree_mesh = create_sprite_mesh(&mut meshes, Vec2::new(3.0, 3.0));
-    let tree_mat = create_sprite_material(&mut materials, tree_texture, AlphaMode::Blend);
-
-    let mut rng = rand::thread_rng();
-    for _ in 0..20 {
-        let x: f32 = rng.gen_range(-20.0..20.0);
-        let z: f32 = rng.gen_range(-20.0..20.0);
-
-        // Keep center clear for base
-        if x.abs() < 5.0 && z.abs() < 5.0 {
-            continue;
+fn load_active_map(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(ActiveMap::load(DEFAULT_MAP, &asset_server));
+}
+
+/// Same tilt as the character and building sprites
+fn billboard_tilt() -> Quat {
+    Quat::from_rotation_x(-45.0f32.to_radians())
+}
+
+/// Sprite meshes and materials for one map spawn, shared between entities using the same image
+struct MapSpawnAssets<'a> {
+    meshes: &'a mut Assets<Mesh>,
+    materials: &'a mut Assets<StandardMaterial>,
+    asset_server: &'a AssetServer,
+    cache: Vec<(String, Vec2, Handle<Mesh>, Handle<StandardMaterial>)>,
+}
+
+impl MapSpawnAssets<'_> {
+    fn sprite(&mut self, path: &str, size: Vec2) -> (Handle<Mesh>, Handle<StandardMaterial>) {
+        if let Some((_, _, mesh, material)) = self.cache.iter().find(|(cached, cached_size, ..)| cached == path && *cached_size == size) {
+            return (mesh.clone(), material.clone());
         }
+        let mesh = create_sprite_mesh(self.meshes, size);
+        let material = create_sprite_material(self.materials, self.asset_server.load(path.to_string()), AlphaMode::Blend);
+        self.cache.push((path.to_string(), size, mesh.clone(), material.clone()));
+        (mesh, material)
+    }
+}
 
+/// Build everything in the active map once its file is loaded
+#[allow(clippy::too_many_arguments)]
+fn spawn_active_map(
+    mut commands: Commands,
+    mut active_map: ResMut<ActiveMap>,
+    maps: Res<Assets<MapAsset>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+    config: Res<ResourceSpawnConfig>,
+    seed: Res<MapSeed>,
+    mut player_q: Query<&mut Transform, With<Player>>,
+) {
+    if active_map.status != MapStatus::Loading {
+        return;
+    }
+    if let LoadState::Failed(error) = asset_server.load_state(&active_map.handle) {
+        error!("Failed to load map '{}': {}", active_map.id, error);
+        active_map.status = MapStatus::Failed;
+        return;
+    }
+    let Some(map) = maps.get(&active_map.handle) else {
+        return;
+    };
+    if let Err(error) = map.validate() {
+        error!("Map '{}' is invalid: {}", active_map.id, error);
+        active_map.status = MapStatus::Failed;
+        return;
+    }
+    info!("Entering {}", map.name);
+
+    // Own stream per map, so the layout doesn't depend on what was rolled before entering it
+    let mut rng = seed.map_rng(&map.id);
+    let tiles = map.build_tiles(&mut rng);
+    spawn_tile_layers(&mut commands, &mut meshes, &mut materials, &asset_server, &tiles);
+    let mut assets = MapSpawnAssets {
+        meshes: &mut meshes,
+        materials: &mut materials,
+        asset_server: &asset_server,
+        cache: Vec::new(),
+    };
+
+    for decoration in &map.decorations {
+        let corner = tile_corner(decoration.tile.into());
+        let footprint = Vec2::new(decoration.footprint[0] as f32, decoration.footprint[1] as f32) * TILE_WORLD_SIZE;
+        let (mesh, material) = assets.sprite(&decoration.sprite, Vec2::splat(decoration.size));
+        let mut entity = commands.spawn((
+            Mesh3d(mesh),
+            MeshMaterial3d(material),
+            Transform::from_xyz(corner.x + footprint.x * 0.5, decoration.size * 0.5, corner.z + footprint.y * 0.5)
+                .with_rotation(billboard_tilt()),
+            Decoration {
+                footprint: if decoration.blocking { footprint } else { Vec2::ZERO },
+            },
+            MapEntity,
+        ));
+        if decoration.blocking {
+            entity.insert(NavObstacle { size: footprint });
+        }
+    }
+
+    for node in &map.resources {
+        let center = tile_center(node.tile.into());
+        let amount = node.amount.or(config.rule(node.resource).map(|rule| rule.amount)).unwrap_or_default();
+        spawn_resource_node(&mut commands, &mut assets, node.resource, Vec2::new(center.x, center.z), amount);
+    }
+    if map.scatter_resources {
+        for (resource_type, position) in scatter_resource_nodes(map, &tiles, &config, &mut rng) {
+            let amount = config.rule(resource_type).map_or(0, |rule| rule.amount);
+            spawn_resource_node(&mut commands, &mut assets, resource_type, position, amount);
+        }
+    }
+
+    for npc in &map.npcs {
+        let size = Vec2::splat(TILE_WORLD_SIZE);
+        let mesh = create_sprite_mesh(assets.meshes, size);
+        // Standing still on the first idle frame of the 4x4 sheet
+        let material = assets.materials.add(StandardMaterial {
+            base_color_texture: Some(asset_server.load(npc.sprite.clone())),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            double_sided: true,
+            cull_mode: None,
+            uv_transform: Affine2::from_scale(Vec2::splat(0.25)),
+            ..default()
+        });
+        commands.spawn((
+            Mesh3d(mesh),
+            MeshMaterial3d(material),
+            Transform::from_translation(tile_center(npc.tile.into()) + Vec3::Y * size.y * 0.5).with_rotation(billboard_tilt()),
+            Npc {
+                id: npc.id.clone(),
+                name: npc.name.clone(),
+            },
+            MapEntity,
+        ));
+    }
+
+    for (index, zone) in map.enemy_zones.iter().enumerate() {
+        let monsters = zone.monsters.iter().filter_map(|id| monster_by_id(id)).collect();
+        commands.spawn((
+            Transform::from_translation(tile_center(zone.center.into())),
+            EnemyZone::new(
+                monsters,
+                zone.radius * TILE_WORLD_SIZE,
+                zone.max_alive,
+                zone.respawn_secs,
+                seed.rng(&format!("zone/{}/{index}", map.id)),
+            ),
+            MapEntity,
+        ));
+    }
+
+    for portal in &map.portals {
+        let sprite = portal.sprite.as_deref().unwrap_or(PORTAL_SPRITE);
+        let (mesh, material) = assets.sprite(sprite, Vec2::splat(TILE_WORLD_SIZE));
         commands.spawn((
-            Mesh3d(tree_mesh.clone()),
-            MeshMaterial3d(tree_mat.clone()),
-            Transform::from_xyz(x, 1.5, z) // Center is at 1.5Y if height is 3.0
-                .with_rotation(Quat::from_rotation_x(-45.0f32.to_radians())), // Tilt back
-            ResourceNode {
-                resource_type: ResourceType::Wood,
-                amount: 100,
+            Mesh3d(mesh),
+            MeshMaterial3d(material),
+            Transform::from_translation(tile_center(portal.tile.into()) + Vec3::Y * TILE_WORLD_SIZE * 0.5)
+                .with_rotation(billboard_tilt()),
+            Portal {
+                target_map: portal.target_map.clone(),
+                target_tile: portal.target_tile.into(),
             },
+            MapEntity,
         ));
     }
+
+    let arrival = match active_map.arrival {
+        Some(tile) if tiles.is_walkable(tile.x, tile.y) => tile,
+        Some(tile) => {
+            warn!("Arrival tile {} on '{}' is not walkable, using the map spawn", tile, active_map.id);
+            map.player_spawn.into()
+        }
+        None => map.player_spawn.into(),
+    };
+    if let Ok(mut transform) = player_q.get_single_mut() {
+        transform.translation = tile_center(arrival);
+    }
+    commands.insert_resource(GroundTiles(tiles));
+    active_map.status = MapStatus::Spawned;
 }
@@ -1,10 +1,22 @@
+pub mod animation;
+pub mod assets;
 pub mod building;
 pub mod camera;
+pub mod combat;
 pub mod enemy;
+pub mod equipment;
 pub mod game;
 pub mod graphics;
 pub mod map;
+pub mod navigation;
+pub mod palette;
 pub mod player;
+pub mod portal;
+pub mod seed;
+pub mod tilemap;
+pub mod tower;
+pub mod walls;
+pub mod wave;
 
 use bevy::prelude::*;
 
@@ -12,15 +24,28 @@ pub struct ClientPlugin;
 
 impl Plugin for ClientPlugin {
     fn build(&self, app: &mut App) {
+        // A seed inserted before the plugin (tests, tools) wins over the command line
+        let seed = app.world().get_resource::<seed::MapSeed>().copied().unwrap_or_else(seed::MapSeed::from_env);
+        info!("Map seed: {}", seed.0);
+        app.insert_resource(seed);
         app.init_resource::<building::BuildMode>();
         app.add_plugins((
             game::GamePlugin,
             camera::CameraPlugin,
             graphics::GraphicsPlugin,
+            animation::AnimationPlugin,
+            equipment::EquipmentPlugin,
+            palette::PalettePlugin,
             map::MapPlugin,
+            navigation::NavigationPlugin,
             player::PlayerPlugin,
             enemy::EnemyPlugin,
+            wave::WavePlugin,
+            combat::CombatPlugin,
             building::BuildingPlugin,
+            tower::TowerPlugin,
+            walls::WallPlugin,
         ));
+        app.add_plugins(portal::PortalPlugin);
     }
 }
@@ -0,0 +1,390 @@
+use bevy::prelude::*;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use crate::client::building::Building;
+use crate::client::player::Player;
+use crate::client::tilemap::GroundTiles;
+use crate::shared::constants::TILE_WORLD_SIZE;
+
+pub struct NavigationPlugin;
+
+impl Plugin for NavigationPlugin {
+    fn build(&self, app: &mut App) {
+        // Sized to the map once its ground tiles exist
+        app.insert_resource(NavGrid::new(Vec2::ZERO, Vec2::ZERO, NAV_CELL_SIZE));
+        app.add_systems(Update, (track_terrain, track_nav_obstacles, update_flow_field).chain());
+    }
+}
+
+/// Two nav cells per tile side (1 world unit each), so a one-tile wall blocks 2x2 cells and tile edges are cell edges
+pub const NAV_CELL_SIZE: f32 = TILE_WORLD_SIZE / 2.0;
+
+/// Path cost of a straight / diagonal step
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+
+/// Blocks nav cells under its ground footprint while it exists
+#[derive(Component, Debug, Clone, Copy)]
+pub struct NavObstacle {
+    /// Footprint on the XZ plane in world units, centred on the entity
+    pub size: Vec2,
+}
+
+/// Walkability grid over the map plus a flow field towards every enemy target
+/// (the player and all buildings). Obstacles are added/removed cell by cell as
+/// entities appear and disappear. When only the targets move, just the cells that
+/// were closest to a removed target (and those a new target is closer to) are
+/// recomputed; a change in obstacles recomputes the whole field.
+#[derive(Resource)]
+pub struct NavGrid {
+    origin: Vec2,
+    cell_size: f32,
+    width: usize,
+    height: usize,
+    /// Number of obstacles covering each cell
+    blockers: Vec<u16>,
+    /// Cells on unwalkable ground (water, lava)
+    terrain: Vec<bool>,
+    /// Path cost to the closest target, `u32::MAX` if unreachable
+    distance: Vec<u32>,
+    /// Target cell each cell's distance leads to, `NO_SOURCE` if unreachable
+    source: Vec<usize>,
+    /// Cells settled by the last flow field update
+    cells_updated: usize,
+    obstacles: HashMap<Entity, Vec<usize>>,
+    targets: Vec<usize>,
+    dirty: bool,
+}
+
+impl NavGrid {
+    /// Grid covering `size` world units from `origin` (minimum X/Z corner)
+    pub fn new(origin: Vec2, size: Vec2, cell_size: f32) -> Self {
+        let width = (size.x / cell_size).ceil() as usize;
+        let height = (size.y / cell_size).ceil() as usize;
+        Self {
+            origin,
+            cell_size,
+            width,
+            height,
+            blockers: vec![0; width * height],
+            terrain: vec![false; width * height],
+            distance: vec![u32::MAX; width * height],
+            source: vec![NO_SOURCE; width * height],
+            cells_updated: 0,
+            obstacles: HashMap::new(),
+            targets: Vec::new(),
+            dirty: true,
+        }
+    }
+
+    /// Cell under a world position, if it is on the grid
+    pub fn cell_at(&self, position: Vec3) -> Option<IVec2> {
+        let cell = ((Vec2::new(position.x, position.z) - self.origin) / self.cell_size).floor().as_ivec2();
+        self.in_bounds(cell).then_some(cell)
+    }
+
+    /// World position of a cell centre (on the ground)
+    pub fn cell_center(&self, cell: IVec2) -> Vec3 {
+        let center = self.origin + (cell.as_vec2() + 0.5) * self.cell_size;
+        Vec3::new(center.x, 0.0, center.y)
+    }
+
+    /// Path cost from a cell to the closest target, None if unreachable or off the grid
+    pub fn distance_at(&self, cell: IVec2) -> Option<u32> {
+        self.index(cell).map(|index| self.distance[index]).filter(|&distance| distance != u32::MAX)
+    }
+
+    /// Cells whose distance the last flow field update had to settle
+    pub fn cells_updated(&self) -> usize {
+        self.cells_updated
+    }
+
+    pub fn is_walkable(&self, cell: IVec2) -> bool {
+        self.index(cell).is_some_and(|index| self.blockers[index] == 0 && !self.terrain[index])
+    }
+
+    /// Grid covering `ground`, with its water and lava blocked
+    pub fn for_ground(ground: &GroundTiles) -> Self {
+        let (width, height) = ground.world_size();
+        let mut grid = Self::new(Vec2::ZERO, Vec2::new(width, height), NAV_CELL_SIZE);
+        grid.set_terrain(ground);
+        grid
+    }
+
+    /// Block every cell whose centre lies on unwalkable ground
+    pub fn set_terrain(&mut self, ground: &GroundTiles) {
+        for index in 0..self.terrain.len() {
+            let center = self.cell_center(self.cell_of(index));
+            self.terrain[index] = !ground.is_walkable_at(center);
+        }
+        self.dirty = true;
+    }
+
+    /// Direction to walk from `position` to get closer to the nearest target,
+    /// or None when off the grid or no target is reachable from here
+    pub fn flow_direction(&self, position: Vec3) -> Option<Vec3> {
+        let cell = self.cell_at(position)?;
+        let mut best = (self.distance[self.index(cell)?], None);
+        for (offset, _) in NEIGHBORS {
+            let next = cell + offset;
+            let Some(index) = self.index(next) else {
+                continue;
+            };
+            if !self.can_step(cell, offset) {
+                continue;
+            }
+            if self.distance[index] < best.0 {
+                best = (self.distance[index], Some(next));
+            }
+        }
+
+        let next = best.1?;
+        let direction = self.cell_center(next) - Vec3::new(position.x, 0.0, position.z);
+        direction.try_normalize()
+    }
+
+    /// Cells covered by a footprint centred on `center`
+    fn footprint(&self, center: Vec3, size: Vec2) -> Vec<usize> {
+        // Shrink slightly so an obstacle flush with a cell edge doesn't claim the neighbour
+        let half = size * 0.5 - Vec2::splat(0.01);
+        let min = self.cell_unclamped(Vec2::new(center.x, center.z) - half);
+        let max = self.cell_unclamped(Vec2::new(center.x, center.z) + half);
+        let mut cells = Vec::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                if let Some(index) = self.index(IVec2::new(x, y)) {
+                    cells.push(index);
+                }
+            }
+        }
+        cells
+    }
+
+    pub fn add_obstacle(&mut self, entity: Entity, center: Vec3, size: Vec2) {
+        self.remove_obstacle(entity);
+        let cells = self.footprint(center, size);
+        for &index in &cells {
+            self.blockers[index] += 1;
+        }
+        self.obstacles.insert(entity, cells);
+        self.dirty = true;
+    }
+
+    pub fn remove_obstacle(&mut self, entity: Entity) {
+        if let Some(cells) = self.obstacles.remove(&entity) {
+            for index in cells {
+                self.blockers[index] = self.blockers[index].saturating_sub(1);
+            }
+            self.dirty = true;
+        }
+    }
+
+    /// Point the flow field at `points` (the player) and at the walkable cells
+    /// around the obstacles of `around` (buildings), so enemies stop at their edge.
+    /// Only recomputed when the targets or the obstacles changed.
+    pub fn update_targets(&mut self, points: impl IntoIterator<Item = Vec3>, around: impl IntoIterator<Item = Entity>) {
+        let mut targets: Vec<usize> = points
+            .into_iter()
+            .filter_map(|point| self.cell_at(point).and_then(|cell| self.index(cell)))
+            .collect();
+        for entity in around {
+            if let Some(cells) = self.obstacles.get(&entity) {
+                targets.extend(self.cells_around(cells));
+            }
+        }
+        targets.sort_unstable();
+        targets.dedup();
+
+        if self.dirty {
+            self.rebuild_flow_field(targets);
+        } else if targets != self.targets {
+            self.retarget_flow_field(targets);
+        }
+    }
+
+    /// Walkable cells touching a block of cells
+    fn cells_around(&self, cells: &[usize]) -> Vec<usize> {
+        let mut around = Vec::new();
+        for &index in cells {
+            let cell = self.cell_of(index);
+            for (offset, _) in NEIGHBORS {
+                if let Some(next_index) = self.index(cell + offset)
+                    && self.is_walkable(cell + offset)
+                {
+                    around.push(next_index);
+                }
+            }
+        }
+        around
+    }
+
+    /// Dijkstra from all target cells at once
+    fn rebuild_flow_field(&mut self, targets: Vec<usize>) {
+        self.distance.fill(u32::MAX);
+        self.source.fill(NO_SOURCE);
+        let mut queue = BinaryHeap::new();
+        for &index in &targets {
+            self.distance[index] = 0;
+            self.source[index] = index;
+            queue.push(Reverse((0, index)));
+        }
+        self.cells_updated = self.propagate(queue);
+        self.targets = targets;
+        self.dirty = false;
+    }
+
+    /// Same obstacles, different targets: only cells that led to a removed target are
+    /// cleared and refilled from their neighbours, and new targets spread until they
+    /// stop being closer than what is already there
+    fn retarget_flow_field(&mut self, targets: Vec<usize>) {
+        // Both lists are sorted and deduplicated
+        let removed: Vec<usize> = self.targets.iter().copied().filter(|index| targets.binary_search(index).is_err()).collect();
+        let added = targets.iter().copied().filter(|index| self.targets.binary_search(index).is_err());
+
+        let mut cleared = Vec::new();
+        if !removed.is_empty() {
+            for index in 0..self.source.len() {
+                if self.source[index] != NO_SOURCE && removed.binary_search(&self.source[index]).is_ok() {
+                    self.distance[index] = u32::MAX;
+                    self.source[index] = NO_SOURCE;
+                    cleared.push(index);
+                }
+            }
+        }
+
+        let mut queue = BinaryHeap::new();
+        for index in added {
+            self.distance[index] = 0;
+            self.source[index] = index;
+            queue.push(Reverse((0, index)));
+        }
+        // The border of the cleared area still knows its way; let it flow back in
+        for &index in &cleared {
+            let cell = self.cell_of(index);
+            for (offset, _) in NEIGHBORS {
+                if let Some(next_index) = self.index(cell + offset)
+                    && self.distance[next_index] != u32::MAX
+                {
+                    queue.push(Reverse((self.distance[next_index], next_index)));
+                }
+            }
+        }
+
+        self.cells_updated = self.propagate(queue);
+        self.targets = targets;
+    }
+
+    /// Relax distances outwards from the queued cells; returns how many cells were settled
+    fn propagate(&mut self, mut queue: BinaryHeap<Reverse<(u32, usize)>>) -> usize {
+        let mut settled = 0;
+        while let Some(Reverse((cost, index))) = queue.pop() {
+            if cost > self.distance[index] {
+                continue;
+            }
+            settled += 1;
+            let cell = self.cell_of(index);
+            for (offset, step) in NEIGHBORS {
+                let next = cell + offset;
+                let Some(next_index) = self.index(next) else {
+                    continue;
+                };
+                if !self.can_step(cell, offset) {
+                    continue;
+                }
+                let next_cost = cost + step;
+                if next_cost < self.distance[next_index] {
+                    self.distance[next_index] = next_cost;
+                    self.source[next_index] = self.source[index];
+                    queue.push(Reverse((next_cost, next_index)));
+                }
+            }
+        }
+        settled
+    }
+
+    /// Diagonal steps may not cut the corner of a blocked cell
+    fn can_step(&self, from: IVec2, offset: IVec2) -> bool {
+        if !self.is_walkable(from + offset) {
+            return false;
+        }
+        offset.x == 0
+            || offset.y == 0
+            || (self.is_walkable(from + IVec2::new(offset.x, 0)) && self.is_walkable(from + IVec2::new(0, offset.y)))
+    }
+
+    fn in_bounds(&self, cell: IVec2) -> bool {
+        cell.x >= 0 && cell.y >= 0 && (cell.x as usize) < self.width && (cell.y as usize) < self.height
+    }
+
+    fn index(&self, cell: IVec2) -> Option<usize> {
+        self.in_bounds(cell).then(|| cell.y as usize * self.width + cell.x as usize)
+    }
+
+    fn cell_of(&self, index: usize) -> IVec2 {
+        IVec2::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    fn cell_unclamped(&self, ground: Vec2) -> IVec2 {
+        ((ground - self.origin) / self.cell_size).floor().as_ivec2()
+    }
+}
+
+/// `NavGrid::source` of a cell no target can reach
+const NO_SOURCE: usize = usize::MAX;
+
+const NEIGHBORS: [(IVec2, u32); 8] = [
+    (IVec2::new(1, 0), STRAIGHT_COST),
+    (IVec2::new(-1, 0), STRAIGHT_COST),
+    (IVec2::new(0, 1), STRAIGHT_COST),
+    (IVec2::new(0, -1), STRAIGHT_COST),
+    (IVec2::new(1, 1), DIAGONAL_COST),
+    (IVec2::new(1, -1), DIAGONAL_COST),
+    (IVec2::new(-1, 1), DIAGONAL_COST),
+    (IVec2::new(-1, -1), DIAGONAL_COST),
+];
+
+/// A new map means a new grid; obstacles that survived the switch are added back
+fn track_terrain(
+    mut grid: ResMut<NavGrid>,
+    ground: Option<Res<GroundTiles>>,
+    obstacle_q: Query<(Entity, &Transform, &NavObstacle)>,
+) {
+    let Some(ground) = ground.filter(|ground| ground.is_changed()) else {
+        return;
+    };
+    *grid = NavGrid::for_ground(&ground);
+    for (entity, transform, obstacle) in obstacle_q.iter() {
+        grid.add_obstacle(entity, transform.translation, obstacle.size);
+    }
+}
+
+/// Incremental updates: only obstacles that appeared, moved or disappeared touch the grid
+#[allow(clippy::type_complexity)]
+fn track_nav_obstacles(
+    mut grid: ResMut<NavGrid>,
+    obstacle_q: Query<(Entity, &Transform, &NavObstacle), Or<(Added<NavObstacle>, Changed<Transform>)>>,
+    mut removed: RemovedComponents<NavObstacle>,
+) {
+    for entity in removed.read() {
+        grid.remove_obstacle(entity);
+    }
+    for (entity, transform, obstacle) in obstacle_q.iter() {
+        grid.add_obstacle(entity, transform.translation, obstacle.size);
+    }
+}
+
+fn update_flow_field(
+    mut grid: ResMut<NavGrid>,
+    player_q: Query<&Transform, With<Player>>,
+    building_q: Query<(Entity, &Transform, Has<NavObstacle>), With<Building>>,
+) {
+    // Buildings without a footprint are walked onto like the player
+    let points = player_q.iter().map(|transform| transform.translation).chain(
+        building_q
+            .iter()
+            .filter(|(_, _, obstacle)| !obstacle)
+            .map(|(_, transform, _)| transform.translation),
+    );
+    let around = building_q.iter().filter(|(_, _, obstacle)| *obstacle).map(|(entity, ..)| entity);
+    grid.update_targets(points, around);
+}
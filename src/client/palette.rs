@@ -0,0 +1,148 @@
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use std::collections::HashMap;
+use crate::client::animation::{Palette, SpriteAnimator, SpriteManifest};
+use crate::client::graphics::SpriteMaterial;
+
+pub struct PalettePlugin;
+
+impl Plugin for PalettePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PaletteVariants>();
+        app.add_systems(Update, apply_palette_swaps);
+    }
+}
+
+/// How far (per channel) a pixel may be from a `color_map` source colour and still be remapped.
+/// Shading inside that range is kept by shifting instead of replacing.
+const PALETTE_MATCH_TOLERANCE: i16 = 48;
+
+/// Recolour this entity's texture with a palette from its sprite manifest
+#[derive(Component)]
+pub struct PaletteSwap {
+    pub palette: String,
+    applied: bool,
+}
+
+impl PaletteSwap {
+    pub fn new(palette: &str) -> Self {
+        Self {
+            palette: palette.to_string(),
+            applied: false,
+        }
+    }
+}
+
+/// Recoloured textures/materials, built once per (source image, palette) and shared.
+/// Only ids are kept: a variant lives as long as sprites use it and is rebuilt if needed again.
+#[derive(Resource, Default)]
+pub struct PaletteVariants(HashMap<(AssetId<Image>, String), PaletteVariant>);
+
+pub struct PaletteVariant {
+    pub image: AssetId<Image>,
+    pub material: AssetId<StandardMaterial>,
+}
+
+fn apply_palette_swaps(
+    mut query: Query<(&mut PaletteSwap, &SpriteAnimator, &mut MeshMaterial3d<StandardMaterial>, Option<&SpriteMaterial>)>,
+    manifests: Res<Assets<SpriteManifest>>,
+    mut images: ResMut<Assets<Image>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut variants: ResMut<PaletteVariants>,
+) {
+    for (mut swap, animator, mut material_handle, sprite_material) in query.iter_mut() {
+        if swap.applied {
+            continue;
+        }
+
+        let Some(manifest) = manifests.get(&animator.manifest) else {
+            continue;
+        };
+        let Some(palette) = manifest.palettes.iter().find(|p| p.name == swap.palette) else {
+            warn!("Palette '{}' not found in manifest '{}'", swap.palette, manifest.id);
+            swap.applied = true;
+            continue;
+        };
+
+        // The mesh may already show a per-frame material; recolour the shared one behind it
+        let base_handle = sprite_material.map_or(&material_handle.0, |sprite_material| &sprite_material.source);
+        let Some(base_material) = materials.get(base_handle).cloned() else {
+            continue;
+        };
+        let Some(texture) = base_material.base_color_texture.clone() else {
+            swap.applied = true;
+            continue;
+        };
+
+        let key = (texture.id(), palette.name.clone());
+        if let Some(material) = variants.0.get(&key).and_then(|variant| materials.get_strong_handle(variant.material)) {
+            material_handle.0 = material;
+            swap.applied = true;
+            continue;
+        }
+
+        // Wait until the source texture is on the CPU side
+        let Some(source) = images.get(&texture) else {
+            continue;
+        };
+        let Some(recolored) = recolor_image(source, palette) else {
+            warn!("Palette swap unsupported for texture format {:?}", source.texture_descriptor.format);
+            swap.applied = true;
+            continue;
+        };
+        let variant_image = images.add(recolored);
+        let variant_material = materials.add(StandardMaterial {
+            base_color_texture: Some(variant_image.clone()),
+            ..base_material
+        });
+        variants.0.insert(key, PaletteVariant {
+            image: variant_image.id(),
+            material: variant_material.id(),
+        });
+        material_handle.0 = variant_material;
+        swap.applied = true;
+    }
+}
+
+/// Build a recoloured copy of an RGBA8 image. Done on the CPU at load time so it works on webgl2.
+pub fn recolor_image(source: &Image, palette: &Palette) -> Option<Image> {
+    if !matches!(
+        source.texture_descriptor.format,
+        TextureFormat::Rgba8UnormSrgb | TextureFormat::Rgba8Unorm
+    ) {
+        return None;
+    }
+
+    let color_map: Vec<([u8; 3], [u8; 3])> = palette
+        .color_map
+        .iter()
+        .filter_map(|(from, to)| Some((parse_hex_color(from)?, parse_hex_color(to)?)))
+        .collect();
+
+    let mut image = source.clone();
+    for pixel in image.data.chunks_exact_mut(4) {
+        if pixel[3] == 0 {
+            continue;
+        }
+        for (from, to) in &color_map {
+            let deltas = [0, 1, 2].map(|i| pixel[i] as i16 - from[i] as i16);
+            if deltas.iter().all(|d| d.abs() <= PALETTE_MATCH_TOLERANCE) {
+                for i in 0..3 {
+                    pixel[i] = (to[i] as i16 + deltas[i]).clamp(0, 255) as u8;
+                }
+                break;
+            }
+        }
+    }
+    Some(image)
+}
+
+/// "#RRGGBB" -> [r, g, b]
+fn parse_hex_color(hex: &str) -> Option<[u8; 3]> {
+    let hex = hex.trim_start_matches('#');
+    if hex.len() != 6 {
+        return None;
+    }
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    Some([(value >> 16) as u8, (value >> 8) as u8, value as u8])
+}
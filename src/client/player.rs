@@ -1,14 +1,27 @@
 use bevy::prelude::*;
 use crate::client::game::GameResources;
 use crate::client::map::{ResourceNode, ResourceType};
-use crate::client::graphics::{Animation, create_sprite_material, create_sprite_mesh};
+use crate::client::animation::{AnimationFinished, Facing, SpriteAnimator};
+use crate::client::camera::cursor_ground_position;
+use crate::client::combat::{DamageEvent, DeathEvent, Health, MeleeAttack};
+use crate::client::enemy::{Dying, Enemy};
+use crate::client::equipment::{PaperDoll, PaperDollLayer};
+use crate::client::graphics::Animation;
+use crate::client::portal::MapTransition;
+use crate::client::tilemap::GroundTiles;
 
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, spawn_player);
-        app.add_systems(Update, (player_input, move_player, gather_resources));
+        app.add_systems(Update, (
+            // Chained so a swing's `MovementTarget` removal lands before movement runs
+            (player_input, player_attack, move_player).chain(),
+            gather_resources,
+            finish_player_attack,
+            player_death,
+        ));
     }
 }
 
@@ -16,7 +29,7 @@ impl Plugin for PlayerPlugin {
 pub struct Player;
 
 #[derive(Component)]
-pub struct MovementTarget(Vec3);
+pub struct MovementTarget(pub Vec3);
 
 #[derive(Component)]
 pub struct Speed(f32);
@@ -26,27 +39,35 @@ pub enum PlayerState {
     Idle,
     Moving,
     Gathering(Entity), // Target Resource Entity
+    Attacking,
+    Dead,
 }
 
 #[derive(Component)]
 pub struct GatherTimer(Timer);
 
+const PLAYER_MAX_HEALTH: u32 = 200;
+const PLAYER_ATTACK_DAMAGE: u32 = 25;
+const PLAYER_ATTACK_RANGE: f32 = 1.8;
+const PLAYER_ATTACK_COOLDOWN: f32 = 0.6;
+
 fn spawn_player(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
     asset_server: Res<AssetServer>,
 ) {
     // Load Player Sprite
     // Path based on ASSETS.md and file check
-    let texture_handle = asset_server.load("characters/warrior/male/spritesheet.png");
+    let manifest_handle = asset_server.load("sprites/manifests/warrior_male.json");
+    let anchors_handle = asset_server.load("sprites/anchors/warrior_male.json");
 
-    // Create Sprite Mesh (Billboard)
-    // Size: 256x256 texture, 4x4 grid -> 64x64 frame.
+    // Paper Doll: base body + starting equipment, each layer is its own quad
     // In world units, let's say 1 unit = 1 meter. 64px could be 2.0 units height?
     // Let's approximate. Standard character height ~1.8m.
-    let mesh_handle = create_sprite_mesh(&mut meshes, Vec2::new(2.0, 2.0));
-    let material_handle = create_sprite_material(&mut materials, texture_handle, AlphaMode::Blend);
+    let paper_doll = PaperDoll::new("male", anchors_handle, Vec2::new(2.0, 2.0))
+        .with(PaperDollLayer::Hair, "short")
+        .with(PaperDollLayer::Pants, "pants_cloth")
+        .with(PaperDollLayer::Armor, "leather_armor")
+        .with(PaperDollLayer::Weapon, "wooden_sword");
 
     // Player Entity (Container)
     commands.spawn((
@@ -54,14 +75,15 @@ fn spawn_player(
         Visibility::default(),
         Player,
         PlayerState::Idle,
+        Facing::default(),
         Speed(6.0),
+        Health::new(PLAYER_MAX_HEALTH),
+        MeleeAttack::new(PLAYER_ATTACK_DAMAGE, PLAYER_ATTACK_RANGE, PLAYER_ATTACK_COOLDOWN),
         GatherTimer(Timer::from_seconds(1.0, TimerMode::Repeating)),
     ))
     .with_children(|parent| {
-        // Sprite Entity
+        // Sprite Entity (drives the paper doll layers)
         parent.spawn((
-            Mesh3d(mesh_handle),
-            MeshMaterial3d(material_handle),
             Transform::from_xyz(0.0, 1.0, 0.0) // Lift up so feet are at (0,0,0) parent
                 .with_rotation(Quat::from_rotation_x(-45.0f32.to_radians())), // Tilt back to face camera (approx)
                 // Note: If using Billboard behavior, we'd use LookAt, but for fixed Iso, a fixed tilt is often used.
@@ -71,7 +93,10 @@ fn spawn_player(
                 // If we put them on a vertical quad, and camera is at 45 deg, it looks foreshortened.
                 // To counteract foreshortening, we can tilt the quad back 45 degrees so it's perpendicular to camera.
                 // Let's try 45 deg tilt.
-            Animation::new(4, 4, 8.0), // 4 rows, 4 cols, 8 FPS
+            Visibility::default(),
+            Animation::new(4, 4, 8.0), // Layout is overwritten by the manifest once loaded
+            SpriteAnimator::new(manifest_handle, "idle"),
+            paper_doll,
         ));
     });
 }
@@ -82,77 +107,68 @@ fn player_input(
     camera_q: Query<(&Camera, &GlobalTransform), With<crate::client::camera::MainCamera>>,
     mut player_q: Query<(Entity, &mut PlayerState), With<Player>>,
     resource_q: Query<(Entity, &GlobalTransform), With<ResourceNode>>,
+    transition: Res<MapTransition>,
     mut commands: Commands,
 ) {
-    // Right click to move or interact
-    if mouse.just_pressed(MouseButton::Right) {
+    // Right click to move or interact, except while switching maps
+    if mouse.just_pressed(MouseButton::Right) && !transition.is_active() {
         let (camera, camera_transform) = camera_q.single();
         let window = windows.single();
 
-        if let Some(cursor_position) = window.cursor_position() {
-            if let Ok(ray) = camera.viewport_to_world(camera_transform, cursor_position) {
-                // Raycast to ground
-                if ray.direction.y.abs() > f32::EPSILON {
-                    let t = -ray.origin.y / ray.direction.y;
-                    if t >= 0.0 {
-                        let target_pos = ray.origin + ray.direction * t;
+        // Raycast to ground
+        if let Some(target_pos) = cursor_ground_position(window, camera, camera_transform) {
+            // Check if we clicked a resource node (simple distance check for now)
+            // In a real game, use raycasting against mesh colliders
+            let mut clicked_resource = None;
+            for (res_entity, res_transform) in resource_q.iter() {
+                // Project resource to Y=0 plane for distance check
+                let res_pos_flat = Vec3::new(res_transform.translation().x, 0.0, res_transform.translation().z);
+                let click_pos_flat = Vec3::new(target_pos.x, 0.0, target_pos.z);
 
-                        // Check if we clicked a resource node (simple distance check for now)
-                        // In a real game, use raycasting against mesh colliders
-                        let mut clicked_resource = None;
-                        for (res_entity, res_transform) in resource_q.iter() {
-                            // Project resource to Y=0 plane for distance check
-                            let res_pos_flat = Vec3::new(res_transform.translation().x, 0.0, res_transform.translation().z);
-                            let click_pos_flat = Vec3::new(target_pos.x, 0.0, target_pos.z);
-
-                            if res_pos_flat.distance(click_pos_flat) < 1.0 { // Radius of click
-                                clicked_resource = Some((res_entity, res_pos_flat));
-                                break;
-                            }
-                        }
+                if res_pos_flat.distance(click_pos_flat) < 1.0 { // Radius of click
+                    clicked_resource = Some((res_entity, res_pos_flat));
+                    break;
+                }
+            }
 
-                        if let Ok((player_entity, mut state)) = player_q.get_single_mut() {
-                            if let Some((res_entity, res_pos)) = clicked_resource {
-                                // Go to resource
-                                commands.entity(player_entity).insert(MovementTarget(res_pos));
-                                *state = PlayerState::Gathering(res_entity);
-                            } else {
-                                // Just move
-                                commands.entity(player_entity).insert(MovementTarget(target_pos));
-                                *state = PlayerState::Moving;
-                            }
-                        }
-                    }
+            if let Ok((player_entity, mut state)) = player_q.get_single_mut()
+                && !matches!(*state, PlayerState::Dead)
+            {
+                if let Some((res_entity, res_pos)) = clicked_resource {
+                    // Go to resource
+                    commands.entity(player_entity).insert(MovementTarget(res_pos));
+                    *state = PlayerState::Gathering(res_entity);
+                } else {
+                    // Just move
+                    commands.entity(player_entity).insert(MovementTarget(target_pos));
+                    *state = PlayerState::Moving;
                 }
             }
         }
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn move_player(
     mut commands: Commands,
-    mut player_q: Query<(Entity, &mut Transform, &Speed, &MovementTarget, &mut PlayerState, &Children), With<Player>>,
-    mut animation_q: Query<&mut Animation>,
+    mut player_q: Query<(Entity, &mut Transform, &Speed, &MovementTarget, &mut PlayerState, &mut Facing, &Children), With<Player>>,
+    mut animator_q: Query<&mut SpriteAnimator>,
+    ground: Option<Res<GroundTiles>>,
     time: Res<Time>,
 ) {
-    if let Ok((entity, mut transform, speed, target, mut state, children)) = player_q.get_single_mut() {
+    if let Ok((entity, mut transform, speed, target, mut state, mut facing, children)) = player_q.get_single_mut() {
+        // A swing owns the animator until it finishes
+        if matches!(*state, PlayerState::Attacking) {
+            return;
+        }
         let direction = target.0 - transform.translation;
         // Ignore Y for movement distance
         let flat_direction = Vec3::new(direction.x, 0.0, direction.z);
         let distance = flat_direction.length();
 
-        let mut current_direction_idx = 0; // Down
-        // Calculate direction index for spritesheet
-        // Grid: Col 0 (Down), Col 1 (Left), Col 2 (Right), Col 3 (Up)
+        // Calculate facing direction
         if distance > 0.1 {
-             let normalized = flat_direction.normalize();
-             if normalized.z.abs() > normalized.x.abs() {
-                 if normalized.z > 0.0 { current_direction_idx = 0; } // Down (+Z)
-                 else { current_direction_idx = 3; } // Up (-Z)
-             } else {
-                 if normalized.x < 0.0 { current_direction_idx = 1; } // Left (-X)
-                 else { current_direction_idx = 2; } // Right (+X)
-             }
+            *facing = Facing::from_direction(flat_direction);
         }
 
         let mut is_moving = false;
@@ -174,6 +190,15 @@ fn move_player(
                  if let PlayerState::Moving = *state {
                     *state = PlayerState::Idle;
                 }
+            } else if ground.as_ref().is_some_and(|ground| {
+                !ground.is_walkable_at(transform.translation + flat_direction.normalize() * move_dist)
+            }) {
+                // Water or lava ahead
+                is_moving = false;
+                commands.entity(entity).remove::<MovementTarget>();
+                if let PlayerState::Moving = *state {
+                    *state = PlayerState::Idle;
+                }
             } else {
                 transform.translation += flat_direction.normalize() * move_dist;
                 // Don't rotate the container transform with LookAt, because that spins the billboard too.
@@ -183,48 +208,17 @@ fn move_player(
         }
 
         // Update Animation
+        // Rows are states, columns are frames (see sprites/manifests/warrior_male.json)
         for child in children.iter() {
-            if let Ok(mut animation) = animation_q.get_mut(*child) {
-                 // Update Row (State)
-                 // Grid: Row 0 (Idle), Row 1 (Walk), Row 2 (Attack), Row 3 (Die)
-                 if is_moving {
-                     animation.current_row = 1; // Walk
-                 } else {
-                     // Check if gathering (Attack animation?)
-                     match *state {
-                         PlayerState::Gathering(_) => animation.current_row = 2, // Attack/Gather
-                         _ => animation.current_row = 0, // Idle
-                     }
-                 }
-
-                 // Update Col (Direction) - BUT wait, the grid is Col=Direction?
-                 // ASSETS.md: "Col 0 (Down) Col 1 (Left) Col 2 (Right) Col 3 (Up)"
-                 // AND "Row 0 (Idle) Row 1 (Walk)..."
-                 // This implies that for a given State (Row), there are 4 Direction frames (Cols).
-                 // Wait, usually it's "Row per Direction" or "Row per Animation".
-                 // If Row 0 is ALL Idle frames, then Col must represent Direction?
-                 // If so, there is NO animation frames (movement of legs) within a direction?
-                 // THAT IS WEIRD for a "spritesheet". Usually a spritesheet has multiple frames for walking.
-                 //
-                 // Let's re-read ASSETS.md carefully:
-                 // "그리드: 4열 × 4행 = 16 프레임"
-                 // "Col 0 (Down) Col 1 (Left) Col 2 (Right) Col 3 (Up)"
-                 // "Row 0 (Idle) Row 1 (Walk) Row 2 (Attack) Row 3 (Die)"
-                 //
-                 // Interpretation A: It's a static image per direction/state. No walking animation cycle?
-                 // Interpretation B: The documentation is simplifying. Maybe it's "Row = Direction", "Col = Frame"?
-                 //
-                 // Let's assume Interpretation A for now (Static sprite per state/dir) because 4x4 is very small.
-                 // If it were animated, it would need more columns (e.g., 4 columns for Down-Walk).
-                 // IF Interpretation A is true, then `current_col` should be `current_direction_idx`.
-                 // And we should NOT auto-increment `current_col` in `graphics.rs`.
-
-                 animation.current_col = current_direction_idx;
-
-                 // Disable auto-play in graphics.rs or handle it here?
-                 // The `animate_sprites` system in graphics.rs increments col.
-                 // I should set `is_playing = false` so it doesn't loop through directions as if they were frames.
-                 animation.is_playing = false;
+            if let Ok(mut animator) = animator_q.get_mut(*child) {
+                if is_moving {
+                    animator.play("walk");
+                } else {
+                    match *state {
+                        PlayerState::Gathering(_) => animator.play("attack"),
+                        _ => animator.play("idle"),
+                    }
+                }
             }
         }
     }
@@ -234,37 +228,117 @@ fn gather_resources(
     mut player_q: Query<(&mut PlayerState, &Transform, &mut GatherTimer), With<Player>>,
     mut resource_q: Query<(&mut ResourceNode, &GlobalTransform)>,
     mut game_resources: ResMut<GameResources>,
-    mut commands: Commands,
     time: Res<Time>,
 ) {
-    if let Ok((mut state, player_transform, mut timer)) = player_q.get_single_mut() {
-        if let PlayerState::Gathering(res_entity) = *state {
-            if let Ok((mut node, res_transform)) = resource_q.get_mut(res_entity) {
-                 let distance = player_transform.translation.distance(res_transform.translation());
+    if let Ok((mut state, player_transform, mut timer)) = player_q.get_single_mut()
+        && let PlayerState::Gathering(res_entity) = *state
+    {
+        if let Ok((mut node, res_transform)) = resource_q.get_mut(res_entity) {
+             let distance = player_transform.translation.distance(res_transform.translation());
 
-                 // Gathering Range
-                 if distance < 2.5 {
-                     timer.0.tick(time.delta());
-                     if timer.0.finished() {
-                         // Add resources
-                         match node.resource_type {
-                             ResourceType::Wood => game_resources.wood += 10,
-                             ResourceType::Gold => game_resources.gold += 10,
-                         }
+             // Gathering Range
+             if distance < 2.5 {
+                 timer.0.tick(time.delta());
+                 if timer.0.finished() {
+                     // Add resources
+                     match node.resource_type {
+                         ResourceType::Wood => game_resources.wood += 10,
+                         ResourceType::Gold => game_resources.gold += 10,
+                     }
 
-                         // Deplete node
-                         if node.amount > 10 {
-                             node.amount -= 10;
-                         } else {
-                             // Despawn
-                             commands.entity(res_entity).despawn_recursive();
-                             *state = PlayerState::Idle;
-                         }
+                     // Deplete node; the map takes care of empty nodes
+                     node.amount = node.amount.saturating_sub(10);
+                     if node.amount == 0 {
+                         *state = PlayerState::Idle;
                      }
                  }
-            } else {
-                // Resource doesn't exist anymore
-                *state = PlayerState::Idle;
+             }
+        } else {
+            // Resource doesn't exist anymore
+            *state = PlayerState::Idle;
+        }
+    }
+}
+
+/// Space: swing at every enemy in front of the player
+#[allow(clippy::type_complexity)]
+fn player_attack(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut player_q: Query<(Entity, &Transform, &Facing, &mut MeleeAttack, &mut PlayerState, &Children), With<Player>>,
+    enemy_q: Query<(Entity, &Transform), (With<Enemy>, Without<Dying>)>,
+    mut animator_q: Query<&mut SpriteAnimator>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    if !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+    let Ok((entity, transform, facing, mut attack, mut state, children)) = player_q.get_single_mut() else {
+        return;
+    };
+    if matches!(*state, PlayerState::Dead) || !attack.trigger() {
+        return;
+    }
+
+    // Attacking interrupts walking and gathering
+    commands.entity(entity).remove::<MovementTarget>();
+    *state = PlayerState::Attacking;
+    for child in children.iter() {
+        if let Ok(mut animator) = animator_q.get_mut(*child) {
+            animator.restart("attack");
+        }
+    }
+
+    for (enemy, enemy_transform) in enemy_q.iter() {
+        if attack.reaches(transform.translation, *facing, enemy_transform.translation) {
+            damage_events.send(DamageEvent {
+                target: enemy,
+                amount: attack.damage,
+                source: Some(entity),
+            });
+        }
+    }
+}
+
+/// Back to idle once the swing animation is over
+fn finish_player_attack(
+    mut finished_events: EventReader<AnimationFinished>,
+    parent_q: Query<&Parent>,
+    mut player_q: Query<&mut PlayerState, With<Player>>,
+    mut animator_q: Query<&mut SpriteAnimator>,
+) {
+    for event in finished_events.read() {
+        if event.state != "attack" {
+            continue;
+        }
+        if let Ok(parent) = parent_q.get(event.entity)
+            && let Ok(mut state) = player_q.get_mut(parent.get())
+            && matches!(*state, PlayerState::Attacking)
+        {
+            *state = PlayerState::Idle;
+            if let Ok(mut animator) = animator_q.get_mut(event.entity) {
+                animator.play("idle");
+            }
+        }
+    }
+}
+
+fn player_death(
+    mut commands: Commands,
+    mut death_events: EventReader<DeathEvent>,
+    mut player_q: Query<(&mut PlayerState, &Children), With<Player>>,
+    mut animator_q: Query<&mut SpriteAnimator>,
+) {
+    for event in death_events.read() {
+        let Ok((mut state, children)) = player_q.get_mut(event.entity) else {
+            continue;
+        };
+        info!("Player died!");
+        *state = PlayerState::Dead;
+        commands.entity(event.entity).remove::<MovementTarget>();
+        for child in children.iter() {
+            if let Ok(mut animator) = animator_q.get_mut(*child) {
+                animator.restart("die");
             }
         }
     }
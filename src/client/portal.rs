@@ -0,0 +1,209 @@
+use bevy::prelude::*;
+use crate::client::map::{ActiveMap, MapEntity, Portal, tile_at};
+use crate::client::player::{MovementTarget, Player, PlayerState};
+use crate::client::tilemap::GroundTiles;
+
+pub struct PortalPlugin;
+
+impl Plugin for PortalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MapTransition>();
+        app.add_systems(Startup, setup_fade_overlay);
+        app.add_systems(Update, (enter_portals, run_map_transition, update_fade_overlay).chain());
+    }
+}
+
+/// Ground distance from a portal's centre that counts as stepping on it
+const PORTAL_TRIGGER_RADIUS: f32 = 0.8;
+
+/// Seconds for the screen to fade out (and back in)
+const FADE_SECS: f32 = 0.4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransitionPhase {
+    #[default]
+    Idle,
+    /// Screen going black; the old map is still there
+    FadeOut,
+    /// Old map gone, waiting for the new one to load and spawn
+    Loading,
+    FadeIn,
+}
+
+/// Moving between maps through a portal
+#[derive(Resource, Debug)]
+pub struct MapTransition {
+    pub phase: TransitionPhase,
+    timer: Timer,
+    target: Option<(String, IVec2)>,
+    /// Map and tile the player left from, reloaded if the target map fails to load or validate
+    origin: Option<(String, IVec2)>,
+    /// Portal the player stands on. A portal fires only when stepped onto,
+    /// so arriving next to the return portal doesn't bounce the player back.
+    touching: Option<Entity>,
+    /// Set on arrival: the next check only records which portal the player stands on
+    arriving: bool,
+}
+
+impl Default for MapTransition {
+    fn default() -> Self {
+        Self {
+            phase: TransitionPhase::Idle,
+            timer: Timer::from_seconds(FADE_SECS, TimerMode::Once),
+            target: None,
+            origin: None,
+            touching: None,
+            arriving: false,
+        }
+    }
+}
+
+impl MapTransition {
+    pub fn is_active(&self) -> bool {
+        self.phase != TransitionPhase::Idle
+    }
+
+    /// How black the screen is (0 = clear)
+    fn fade(&self) -> f32 {
+        match self.phase {
+            TransitionPhase::Idle => 0.0,
+            TransitionPhase::FadeOut => self.timer.fraction(),
+            TransitionPhase::Loading => 1.0,
+            TransitionPhase::FadeIn => 1.0 - self.timer.fraction(),
+        }
+    }
+}
+
+/// Full-screen black panel drawn over everything during transitions
+#[derive(Component)]
+struct FadeOverlay;
+
+fn setup_fade_overlay(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.0)),
+        GlobalZIndex(i32::MAX),
+        Visibility::Hidden,
+        FadeOverlay,
+    ));
+}
+
+fn enter_portals(
+    mut commands: Commands,
+    mut transition: ResMut<MapTransition>,
+    mut player_q: Query<(Entity, &Transform, &mut PlayerState), With<Player>>,
+    portal_q: Query<(Entity, &Transform, &Portal)>,
+) {
+    if transition.is_active() {
+        return;
+    }
+    let Ok((player, player_transform, mut state)) = player_q.get_single_mut() else {
+        return;
+    };
+    if matches!(*state, PlayerState::Dead) {
+        return;
+    }
+
+    let position = player_transform.translation;
+    let touching = portal_q.iter().find(|(_, transform, _)| {
+        Vec2::new(transform.translation.x - position.x, transform.translation.z - position.z).length() < PORTAL_TRIGGER_RADIUS
+    });
+    let previous = transition.touching;
+    transition.touching = touching.map(|(entity, ..)| entity);
+    if std::mem::take(&mut transition.arriving) {
+        return;
+    }
+
+    let Some((entity, _, portal)) = touching else {
+        return;
+    };
+    if previous == Some(entity) {
+        return;
+    }
+
+    info!("Portal to {}", portal.target_map);
+    transition.target = Some((portal.target_map.clone(), portal.target_tile));
+    transition.phase = TransitionPhase::FadeOut;
+    transition.timer.reset();
+    commands.entity(player).remove::<MovementTarget>();
+    *state = PlayerState::Idle;
+}
+
+fn run_map_transition(
+    mut commands: Commands,
+    mut transition: ResMut<MapTransition>,
+    mut active_map: ResMut<ActiveMap>,
+    map_entity_q: Query<Entity, With<MapEntity>>,
+    player_q: Query<&Transform, With<Player>>,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+) {
+    match transition.phase {
+        TransitionPhase::Idle => {}
+        TransitionPhase::FadeOut => {
+            transition.timer.tick(time.delta());
+            if !transition.timer.finished() {
+                return;
+            }
+            let Some((map_id, tile)) = transition.target.take() else {
+                transition.phase = TransitionPhase::FadeIn;
+                transition.timer.reset();
+                return;
+            };
+            for entity in map_entity_q.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+            commands.remove_resource::<GroundTiles>();
+            transition.origin = player_q
+                .get_single()
+                .ok()
+                .map(|transform| (active_map.id.clone(), tile_at(transform.translation)));
+            *active_map = ActiveMap::load_at(&map_id, tile, &asset_server);
+            transition.phase = TransitionPhase::Loading;
+        }
+        TransitionPhase::Loading => {
+            if active_map.has_failed() {
+                // Didn't load or isn't valid (already logged). Go back where the player came from;
+                // if that fails too, just show the screen again
+                if let Some((map_id, tile)) = transition.origin.take() {
+                    *active_map = ActiveMap::load_at(&map_id, tile, &asset_server);
+                } else {
+                    transition.phase = TransitionPhase::FadeIn;
+                    transition.timer.reset();
+                }
+                return;
+            }
+            if active_map.is_spawned() {
+                transition.origin = None;
+                transition.phase = TransitionPhase::FadeIn;
+                transition.timer.reset();
+                transition.arriving = true;
+            }
+        }
+        TransitionPhase::FadeIn => {
+            transition.timer.tick(time.delta());
+            if transition.timer.finished() {
+                transition.phase = TransitionPhase::Idle;
+            }
+        }
+    }
+}
+
+fn update_fade_overlay(
+    transition: Res<MapTransition>,
+    mut overlay_q: Query<(&mut BackgroundColor, &mut Visibility), With<FadeOverlay>>,
+) {
+    if !transition.is_changed() {
+        return;
+    }
+    let fade = transition.fade();
+    for (mut color, mut visibility) in overlay_q.iter_mut() {
+        color.0 = Color::BLACK.with_alpha(fade);
+        *visibility = if fade > 0.0 { Visibility::Inherited } else { Visibility::Hidden };
+    }
+}
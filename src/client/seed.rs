@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+/// Seed for everything random in the world (map layout, waves).
+/// Set with `--seed <n>` on native or `?seed=<n>` in the page URL on the web;
+/// otherwise a random one is picked and logged so the run can be replayed.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapSeed(pub u64);
+
+impl MapSeed {
+    /// Seed requested by the player, or a fresh random one
+    pub fn from_env() -> Self {
+        match requested_seed() {
+            Some(text) => match text.parse() {
+                Ok(seed) => Self(seed),
+                Err(_) => {
+                    warn!("Invalid seed '{}', using a random one", text);
+                    Self::random()
+                }
+            },
+            None => Self::random(),
+        }
+    }
+
+    pub fn random() -> Self {
+        Self(rand::thread_rng().r#gen())
+    }
+
+    /// Generator for one named stream (`map/<id>`, `waves/3`, ...). Each stream only depends on
+    /// the seed and its name, so one system drawing more numbers never shifts what another gets.
+    /// Use these instead of `thread_rng` for anything that shapes the world.
+    pub fn rng(self, stream: &str) -> SmallRng {
+        SmallRng::seed_from_u64(self.0 ^ stream_hash(stream))
+    }
+
+    /// Stream for the layout of one map (ground patches, scattered resources)
+    pub fn map_rng(self, map_id: &str) -> SmallRng {
+        self.rng(&format!("map/{map_id}"))
+    }
+}
+
+/// FNV-1a: unlike `DefaultHasher` it is fixed, so a seed means the same world on every build
+fn stream_hash(stream: &str) -> u64 {
+    stream.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// `--seed <n>` or `--seed=<n>`
+#[cfg(not(all(target_arch = "wasm32", feature = "wasm")))]
+fn requested_seed() -> Option<String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--seed" {
+            return args.next();
+        }
+        if let Some(value) = arg.strip_prefix("--seed=") {
+            return Some(value.to_string());
+        }
+    }
+    None
+}
+
+/// `?seed=<n>` in the page URL
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+fn requested_seed() -> Option<String> {
+    let search = web_sys::window()?.location().search().ok()?;
+    web_sys::UrlSearchParams::new_with_str(&search).ok()?.get("seed")
+}
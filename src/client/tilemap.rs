@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+use crate::client::map::MapEntity;
+use crate::shared::constants::{TILE_WORLD_SIZE, Z_LAYER_TILE};
+use crate::shared::tilemap::{TileMap, TileType, tile_center_to_world, tile_to_world, world_to_tile};
+
+/// Ground tiles of the current map
+#[derive(Resource, Debug, Clone, Deref, DerefMut)]
+pub struct GroundTiles(pub TileMap);
+
+impl GroundTiles {
+    pub fn tile_at(&self, position: Vec3) -> IVec2 {
+        world_to_tile(position.x, position.z).into()
+    }
+
+    /// World position (on the ground) of a tile's centre
+    pub fn tile_center(&self, tile: IVec2) -> Vec3 {
+        let (x, z) = tile_center_to_world(tile.x, tile.y);
+        Vec3::new(x, 0.0, z)
+    }
+
+    /// World position (on the ground) of the middle of the map
+    pub fn world_center(&self) -> Vec3 {
+        let (x, z) = self.0.world_center();
+        Vec3::new(x, 0.0, z)
+    }
+
+    /// Can units stand at this world position?
+    pub fn is_walkable_at(&self, position: Vec3) -> bool {
+        let tile = self.tile_at(position);
+        self.0.is_walkable(tile.x, tile.y)
+    }
+}
+
+/// One batched mesh holding every tile of a type
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TileLayer {
+    pub tile_type: TileType,
+}
+
+/// Tile corners in quad order: -X/-Z, +X/-Z, +X/+Z, -X/+Z
+const TILE_CORNERS: [Vec2; 4] = [Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(1.0, 1.0), Vec2::new(0.0, 1.0)];
+
+/// The tile textures are isometric diamonds. Seen from the camera the -X/-Z corner
+/// is the top of the diamond, +X/-Z the right, +X/+Z the bottom and -X/+Z the left.
+/// These UVs sit a little inside the top face so the slab sides never show.
+const TILE_FACE_UV: [[f32; 2]; 4] = [[0.5, 0.22], [0.92, 0.49], [0.5, 0.76], [0.08, 0.49]];
+
+/// Spawn one mesh per tile type present in `tiles`
+pub fn spawn_tile_layers(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    asset_server: &AssetServer,
+    tiles: &TileMap,
+) {
+    for tile_type in TileType::ALL {
+        let Some(mesh) = tile_layer_mesh(tiles, tile_type) else {
+            continue;
+        };
+        commands.spawn((
+            Mesh3d(meshes.add(mesh)),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color_texture: Some(asset_server.load(tile_type.texture())),
+                perceptual_roughness: 1.0,
+                ..default()
+            })),
+            Transform::default(),
+            TileLayer { tile_type },
+            MapEntity,
+        ));
+    }
+}
+
+fn tile_layer_mesh(tiles: &TileMap, tile_type: TileType) -> Option<Mesh> {
+    let mut positions = Vec::new();
+    let mut uvs = Vec::new();
+    let mut indices = Vec::new();
+
+    for (x, y, _) in tiles.iter().filter(|(_, _, tile)| *tile == tile_type) {
+        let (corner_x, corner_z) = tile_to_world(x, y);
+        let base = positions.len() as u32;
+        for (corner, uv) in TILE_CORNERS.iter().zip(TILE_FACE_UV) {
+            positions.push([
+                corner_x + corner.x * TILE_WORLD_SIZE,
+                Z_LAYER_TILE,
+                corner_z + corner.y * TILE_WORLD_SIZE,
+            ]);
+            uvs.push(uv);
+        }
+        // Counter-clockwise seen from above
+        indices.extend([base, base + 3, base + 2, base, base + 2, base + 1]);
+    }
+
+    if positions.is_empty() {
+        return None;
+    }
+    let normals = vec![[0.0, 1.0, 0.0]; positions.len()];
+    Some(
+        Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default())
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+            .with_inserted_indices(Indices::U32(indices)),
+    )
+}
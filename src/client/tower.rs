@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+use crate::client::building::{BuildMode, Building, BuildingType};
+use crate::client::combat::DamageEvent;
+use crate::client::enemy::{Dying, Enemy};
+
+pub struct TowerPlugin;
+
+impl Plugin for TowerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_projectile_assets);
+        app.add_systems(Update, (
+            arm_towers,
+            tower_fire,
+            move_projectiles,
+            draw_tower_ranges,
+        ));
+    }
+}
+
+const TOWER_RANGE: f32 = 8.0;
+const TOWER_DAMAGE: u32 = 15;
+const TOWER_COOLDOWN: f32 = 1.0;
+/// World units per second
+const PROJECTILE_SPEED: f32 = 12.0;
+/// Projectiles leave from the top of the tower sprite
+const TOWER_MUZZLE_HEIGHT: f32 = 1.0;
+/// Projectiles aim at the middle of the target, not its feet
+const TARGET_AIM_HEIGHT: f32 = 0.5;
+
+const TOWER_RANGE_COLOR: Color = Color::srgba(0.4, 0.7, 1.0, 0.8);
+
+/// Shoots the nearest enemy in range
+#[derive(Component, Debug, Clone)]
+pub struct Tower {
+    pub range: f32,
+    pub damage: u32,
+    pub cooldown: Timer,
+}
+
+impl Default for Tower {
+    fn default() -> Self {
+        Self {
+            range: TOWER_RANGE,
+            damage: TOWER_DAMAGE,
+            cooldown: Timer::from_seconds(TOWER_COOLDOWN, TimerMode::Once),
+        }
+    }
+}
+
+/// Homing shot; deals its damage on arrival
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Projectile {
+    pub target: Entity,
+    pub source: Entity,
+    pub damage: u32,
+    pub speed: f32,
+}
+
+#[derive(Resource)]
+struct ProjectileAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+fn setup_projectile_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(ProjectileAssets {
+        mesh: meshes.add(Sphere::new(0.15)),
+        material: materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.8, 0.3),
+            emissive: LinearRgba::rgb(4.0, 2.5, 0.8),
+            unlit: true,
+            ..default()
+        }),
+    });
+}
+
+/// Every placed tower building gets its weapon
+fn arm_towers(mut commands: Commands, building_q: Query<(Entity, &Building), Added<Building>>) {
+    for (entity, building) in building_q.iter() {
+        if building.building_type == BuildingType::Tower {
+            commands.entity(entity).insert(Tower::default());
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn tower_fire(
+    mut commands: Commands,
+    mut tower_q: Query<(Entity, &Transform, &mut Tower)>,
+    enemy_q: Query<(Entity, &Transform), (With<Enemy>, Without<Dying>, Without<Tower>)>,
+    assets: Option<Res<ProjectileAssets>>,
+    time: Res<Time>,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+
+    for (entity, transform, mut tower) in tower_q.iter_mut() {
+        tower.cooldown.tick(time.delta());
+        if !tower.cooldown.finished() {
+            continue;
+        }
+
+        let position = transform.translation;
+        let nearest = enemy_q
+            .iter()
+            .map(|(enemy, enemy_transform)| {
+                let offset = enemy_transform.translation - position;
+                (enemy, Vec2::new(offset.x, offset.z).length_squared())
+            })
+            .filter(|(_, distance_sq)| *distance_sq <= tower.range * tower.range)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+
+        if let Some((target, _)) = nearest {
+            tower.cooldown.reset();
+            commands.spawn((
+                Mesh3d(assets.mesh.clone()),
+                MeshMaterial3d(assets.material.clone()),
+                Transform::from_translation(position + Vec3::Y * TOWER_MUZZLE_HEIGHT),
+                Projectile {
+                    target,
+                    source: entity,
+                    damage: tower.damage,
+                    speed: PROJECTILE_SPEED,
+                },
+            ));
+        }
+    }
+}
+
+fn move_projectiles(
+    mut commands: Commands,
+    mut projectile_q: Query<(Entity, &mut Transform, &Projectile)>,
+    target_q: Query<&Transform, (Without<Projectile>, Without<Dying>)>,
+    mut damage_events: EventWriter<DamageEvent>,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, projectile) in projectile_q.iter_mut() {
+        // Target died or despawned on the way
+        let Ok(target) = target_q.get(projectile.target) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+
+        let aim = target.translation + Vec3::Y * TARGET_AIM_HEIGHT;
+        let to_target = aim - transform.translation;
+        let step = projectile.speed * time.delta_secs();
+        if to_target.length() <= step {
+            damage_events.send(DamageEvent {
+                target: projectile.target,
+                amount: projectile.damage,
+                source: Some(projectile.source),
+            });
+            commands.entity(entity).despawn_recursive();
+        } else {
+            transform.translation += to_target.normalize() * step;
+        }
+    }
+}
+
+/// Range rings for placed towers and the tower ghost while in build mode
+fn draw_tower_ranges(
+    mut gizmos: Gizmos,
+    build_mode: Res<BuildMode>,
+    tower_q: Query<(&Transform, &Tower)>,
+    ghost_q: Query<&Transform>,
+) {
+    if !build_mode.active {
+        return;
+    }
+
+    let mut ring = |position: Vec3, range: f32| {
+        let center = Vec3::new(position.x, 0.05, position.z);
+        // Circles are drawn in the XY plane; lay them flat on the ground
+        gizmos.circle(Isometry3d::new(center, Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)), range, TOWER_RANGE_COLOR);
+    };
+
+    for (transform, tower) in tower_q.iter() {
+        ring(transform.translation, tower.range);
+    }
+    if build_mode.building_type == BuildingType::Tower
+        && let Some(ghost) = build_mode.ghost_entity
+        && let Ok(transform) = ghost_q.get(ghost)
+    {
+        ring(transform.translation, TOWER_RANGE);
+    }
+}
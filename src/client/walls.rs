@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+use crate::client::building::{BUILDING_HEIGHT, Building, BuildingType, TileOccupancy};
+use crate::shared::constants::TILE_WORLD_SIZE;
+
+pub struct WallPlugin;
+
+impl Plugin for WallPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_wall_meshes);
+        app.add_systems(Update, connect_walls);
+    }
+}
+
+/// Width of a wall pillar as a fraction of the tile
+pub const WALL_PILLAR_FRACTION: f32 = 0.5;
+
+/// Thickness of the segment joining two pillars, as a fraction of the tile
+const WALL_CONNECTOR_FRACTION: f32 = 0.4;
+
+/// Connectors are a bit lower than the pillars so the joints stay readable
+const WALL_CONNECTOR_HEIGHT: f32 = BUILDING_HEIGHT * 0.85;
+
+/// Neighbour directions in bit order: +X, -X, +Z, -Z
+const WALL_DIRECTIONS: [IVec2; 4] = [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y];
+
+/// Which neighbouring tiles hold walls (bit per `WALL_DIRECTIONS` entry)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WallConnections(pub u8);
+
+/// Segment from a wall pillar to the edge of its tile
+#[derive(Component)]
+struct WallConnector;
+
+#[derive(Resource)]
+struct WallMeshes {
+    along_x: Handle<Mesh>,
+    along_z: Handle<Mesh>,
+}
+
+fn setup_wall_meshes(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
+    let length = TILE_WORLD_SIZE * (1.0 - WALL_PILLAR_FRACTION) * 0.5;
+    let thickness = TILE_WORLD_SIZE * WALL_CONNECTOR_FRACTION;
+    commands.insert_resource(WallMeshes {
+        along_x: meshes.add(Cuboid::new(length, WALL_CONNECTOR_HEIGHT, thickness)),
+        along_z: meshes.add(Cuboid::new(thickness, WALL_CONNECTOR_HEIGHT, length)),
+    });
+}
+
+/// Rebuild connectors whenever a building appears or disappears.
+/// Each wall draws its own half of every joint, so two neighbours meet at the tile edge.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn connect_walls(
+    mut commands: Commands,
+    added_q: Query<(), Added<Building>>,
+    mut removed: RemovedComponents<Building>,
+    occupancy: Res<TileOccupancy>,
+    wall_q: Query<(Entity, &Building, &MeshMaterial3d<StandardMaterial>, Option<&WallConnections>, Option<&Children>)>,
+    building_q: Query<&Building>,
+    connector_q: Query<(), With<WallConnector>>,
+    meshes: Option<Res<WallMeshes>>,
+) {
+    let removed_any = removed.read().count() > 0;
+    if added_q.is_empty() && !removed_any {
+        return;
+    }
+    let Some(meshes) = meshes else {
+        return;
+    };
+
+    let is_wall = |tile: IVec2| {
+        occupancy
+            .get(tile)
+            .and_then(|entity| building_q.get(entity).ok())
+            .is_some_and(|building| building.building_type == BuildingType::Wall)
+    };
+
+    for (entity, building, material, connections, children) in wall_q.iter() {
+        if building.building_type != BuildingType::Wall {
+            continue;
+        }
+
+        let mut mask = 0;
+        for (bit, direction) in WALL_DIRECTIONS.iter().enumerate() {
+            if is_wall(building.tile + *direction) {
+                mask |= 1 << bit;
+            }
+        }
+        let mask = WallConnections(mask);
+        if connections == Some(&mask) {
+            continue;
+        }
+
+        if let Some(children) = children {
+            for child in children.iter() {
+                if connector_q.contains(*child) {
+                    commands.entity(*child).despawn_recursive();
+                }
+            }
+        }
+
+        let offset = TILE_WORLD_SIZE * (1.0 + WALL_PILLAR_FRACTION) * 0.25;
+        let drop = (WALL_CONNECTOR_HEIGHT - BUILDING_HEIGHT) * 0.5;
+        commands.entity(entity).insert(mask).with_children(|parent| {
+            for (bit, direction) in WALL_DIRECTIONS.iter().enumerate() {
+                if mask.0 & (1 << bit) == 0 {
+                    continue;
+                }
+                let mesh = if direction.x != 0 { meshes.along_x.clone() } else { meshes.along_z.clone() };
+                parent.spawn((
+                    Mesh3d(mesh),
+                    // Share the wall's material so damage tint covers the joints too
+                    MeshMaterial3d(material.0.clone()),
+                    Transform::from_xyz(direction.x as f32 * offset, drop, direction.y as f32 * offset),
+                    WallConnector,
+                ));
+            }
+        });
+    }
+}
@@ -0,0 +1,292 @@
+use bevy::prelude::*;
+use rand::Rng;
+use rand::rngs::SmallRng;
+use serde::Deserialize;
+use crate::client::assets::JsonAssetLoader;
+use crate::client::enemy::{Elite, MonsterSpawner};
+use crate::client::map::{ActiveMap, MapAsset};
+use crate::client::seed::MapSeed;
+use crate::client::tilemap::GroundTiles;
+use crate::shared::data::monsters::{MonsterDef, monster_by_id};
+
+pub struct WavePlugin;
+
+impl Plugin for WavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<WaveSchedule>()
+            .register_asset_loader(JsonAssetLoader::<WaveSchedule>::default())
+            .add_systems(Startup, load_wave_schedule)
+            .add_systems(Update, (pause_waves, start_waves, spawn_wave_groups).chain());
+    }
+}
+
+/// Survival mode schedule used by default
+const SURVIVAL_SCHEDULE_PATH: &str = "waves/survival.json";
+
+/// Survival wave schedule (`waves/*.json`)
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+pub struct WaveSchedule {
+    /// Seconds before the first wave
+    pub first_wave_delay: f32,
+    /// Seconds between waves, unless a wave sets its own `duration`
+    pub wave_interval: f32,
+    /// Distance from the map centre monsters appear at
+    pub spawn_radius: f32,
+    pub waves: Vec<WaveDef>,
+    /// What happens after the last listed wave. Without it the schedule ends.
+    #[serde(default)]
+    pub endless: Option<EndlessRule>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct WaveDef {
+    #[serde(default)]
+    pub boss: bool,
+    /// Overrides `wave_interval` for the countdown after this wave starts
+    #[serde(default)]
+    pub duration: Option<f32>,
+    pub groups: Vec<SpawnGroup>,
+}
+
+/// `count` monsters of one type, spawned together `delay` seconds after the wave starts
+#[derive(Deserialize, Debug, Clone)]
+pub struct SpawnGroup {
+    /// Monster id from `shared::data::monsters`
+    pub monster: String,
+    pub count: u32,
+    #[serde(default)]
+    pub spawn: SpawnPoint,
+    #[serde(default)]
+    pub delay: f32,
+    /// Manifest palette; recoloured monsters are elites
+    #[serde(default)]
+    pub palette: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SpawnPoint {
+    /// Spread evenly around the spawn circle
+    #[default]
+    Ring,
+    /// Random positions on the spawn circle
+    Random,
+    North,
+    South,
+    East,
+    West,
+    /// Ground position `[x, z]` relative to the map centre
+    Point([f32; 2]),
+}
+
+/// Loop the tail of the schedule with more monsters each cycle
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct EndlessRule {
+    /// First wave number (1-based) of the repeated block
+    pub repeat_from: usize,
+    /// Extra monsters per cycle, as a fraction of the listed counts
+    pub count_growth: f32,
+}
+
+impl WaveSchedule {
+    /// Definition for wave `number` (1-based) and the count multiplier for it
+    pub fn wave(&self, number: u32) -> Option<(&WaveDef, f32)> {
+        let index = number.checked_sub(1)? as usize;
+        if let Some(wave) = self.waves.get(index) {
+            return Some((wave, 1.0));
+        }
+
+        let endless = self.endless.filter(|_| !self.waves.is_empty())?;
+        let start = endless.repeat_from.clamp(1, self.waves.len()) - 1;
+        let block = self.waves.len() - start;
+        let past = index - self.waves.len();
+        let cycle = past / block + 1;
+        let wave = &self.waves[start + past % block];
+        Some((wave, 1.0 + endless.count_growth * cycle as f32))
+    }
+
+    fn duration(&self, wave: &WaveDef) -> f32 {
+        wave.duration.unwrap_or(self.wave_interval)
+    }
+}
+
+impl SpawnPoint {
+    /// Offset from the map centre of monster `index` out of `count`
+    pub fn position(self, index: u32, count: u32, radius: f32, rng: &mut impl Rng) -> Vec3 {
+        let (x, z) = match self {
+            SpawnPoint::Ring => {
+                let angle = (index as f32 / count.max(1) as f32) * std::f32::consts::TAU;
+                (angle.cos() * radius, angle.sin() * radius)
+            }
+            SpawnPoint::Random => {
+                let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                (angle.cos() * radius, angle.sin() * radius)
+            }
+            // Screen up is -Z
+            SpawnPoint::North => (rng.gen_range(-radius..=radius), -radius),
+            SpawnPoint::South => (rng.gen_range(-radius..=radius), radius),
+            SpawnPoint::East => (radius, rng.gen_range(-radius..=radius)),
+            SpawnPoint::West => (-radius, rng.gen_range(-radius..=radius)),
+            SpawnPoint::Point([x, z]) => (x, z),
+        };
+        Vec3::new(x, 0.0, z)
+    }
+}
+
+#[derive(Resource)]
+pub struct WaveManager {
+    pub schedule: Handle<WaveSchedule>,
+    /// Counts down to the next wave
+    pub timer: Timer,
+    pub wave_count: u32,
+    /// Is the current wave a boss wave?
+    pub boss_wave: bool,
+    /// Groups of the current wave still waiting for their delay
+    pending: Vec<PendingGroup>,
+    started: bool,
+    /// The player is on a map without `survival_waves` (towns are safe); nothing counts down
+    paused: bool,
+}
+
+struct PendingGroup {
+    monster: &'static MonsterDef,
+    count: u32,
+    spawn: SpawnPoint,
+    palette: Option<String>,
+    delay: Timer,
+    /// Seeded from the wave number, so positions don't depend on when the wave starts
+    rng: SmallRng,
+}
+
+impl WaveManager {
+    pub fn new(schedule: Handle<WaveSchedule>) -> Self {
+        Self {
+            schedule,
+            timer: Timer::from_seconds(0.0, TimerMode::Once),
+            wave_count: 0,
+            boss_wave: false,
+            pending: Vec::new(),
+            started: false,
+            paused: true,
+        }
+    }
+
+    /// Seconds until the next wave, or None before the schedule has loaded / after it ended /
+    /// while paused off a survival map
+    pub fn next_wave_in(&self) -> Option<f32> {
+        (self.started && !self.paused && !self.timer.finished()).then(|| self.timer.remaining_secs())
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+}
+
+fn load_wave_schedule(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(WaveManager::new(asset_server.load(SURVIVAL_SCHEDULE_PATH)));
+}
+
+/// Waves only run while the player is on a spawned map with `survival_waves`
+fn pause_waves(
+    mut wave_manager: ResMut<WaveManager>,
+    active_map: Option<Res<ActiveMap>>,
+    maps: Res<Assets<MapAsset>>,
+) {
+    let survival = active_map
+        .filter(|active_map| active_map.is_spawned())
+        .and_then(|active_map| maps.get(&active_map.handle))
+        .is_some_and(|map| map.survival_waves);
+    if wave_manager.paused == survival {
+        wave_manager.paused = !survival;
+    }
+}
+
+fn start_waves(
+    mut wave_manager: ResMut<WaveManager>,
+    schedules: Res<Assets<WaveSchedule>>,
+    seed: Res<MapSeed>,
+    time: Res<Time>,
+) {
+    if wave_manager.paused {
+        return;
+    }
+    let Some(schedule) = schedules.get(&wave_manager.schedule) else {
+        return;
+    };
+
+    if !wave_manager.started {
+        wave_manager.started = true;
+        wave_manager.timer = Timer::from_seconds(schedule.first_wave_delay, TimerMode::Once);
+        return;
+    }
+
+    wave_manager.timer.tick(time.delta());
+    if !wave_manager.timer.just_finished() {
+        return;
+    }
+
+    let number = wave_manager.wave_count + 1;
+    let Some((wave, multiplier)) = schedule.wave(number) else {
+        info!("Survival schedule complete");
+        return;
+    };
+
+    wave_manager.wave_count = number;
+    wave_manager.boss_wave = wave.boss;
+    wave_manager.timer = Timer::from_seconds(schedule.duration(wave), TimerMode::Once);
+    if wave.boss {
+        info!("Boss Wave {} Started!", number);
+    } else {
+        info!("Wave {} Started!", number);
+    }
+
+    for (index, group) in wave.groups.iter().enumerate() {
+        let Some(monster) = monster_by_id(&group.monster) else {
+            warn!("Wave {}: unknown monster '{}'", number, group.monster);
+            continue;
+        };
+        wave_manager.pending.push(PendingGroup {
+            monster,
+            count: (group.count as f32 * multiplier).round() as u32,
+            spawn: group.spawn,
+            palette: group.palette.clone(),
+            delay: Timer::from_seconds(group.delay, TimerMode::Once),
+            rng: seed.rng(&format!("waves/{number}/{index}")),
+        });
+    }
+}
+
+fn spawn_wave_groups(
+    mut commands: Commands,
+    mut wave_manager: ResMut<WaveManager>,
+    schedules: Res<Assets<WaveSchedule>>,
+    mut spawner: MonsterSpawner,
+    ground: Option<Res<GroundTiles>>,
+    time: Res<Time>,
+) {
+    if wave_manager.paused {
+        return;
+    }
+    let center = ground.map_or(Vec3::ZERO, |ground| ground.world_center());
+    let Some(radius) = schedules.get(&wave_manager.schedule).map(|schedule| schedule.spawn_radius) else {
+        return;
+    };
+    wave_manager.pending.retain_mut(|group| {
+        group.delay.tick(time.delta());
+        if !group.delay.finished() {
+            return true;
+        }
+
+        for i in 0..group.count {
+            let position = center + group.spawn.position(i, group.count, radius, &mut group.rng);
+            let entity = spawner.spawn(&mut commands, group.monster, position, group.palette.as_deref());
+            if group.palette.is_some() {
+                commands.entity(entity).insert(Elite);
+            }
+        }
+        if group.palette.is_some() || group.monster.is_boss() {
+            info!("{} appears!", group.monster.name);
+        }
+        false
+    });
+}
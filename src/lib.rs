@@ -6,3 +6,6 @@ pub mod shared;
 
 #[cfg(feature = "client")]
 pub mod client;
+
+#[cfg(feature = "server")]
+pub mod server;
@@ -0,0 +1,231 @@
+//! Auth - 계정 등록과 로그인
+//!
+//! 비밀번호는 argon2로 해시해 `users.password_hash`에 저장하고,
+//! 로그인하면 HS256 JWT를 발급합니다.
+
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use axum::extract::{FromRequestParts, State};
+use axum::http::request::Parts;
+use axum::http::{StatusCode, header};
+use axum::routing::post;
+use axum::{Json, Router};
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+use uuid::Uuid;
+use crate::server::error::{ApiError, is_unique_violation};
+use crate::server::state::AppState;
+use crate::shared::domain::auth::{LoginRequest, LoginResponse, PASSWORD_MAX_LEN, RegisterRequest, UserResponse};
+
+/// 토큰 유효 기간 (초)
+pub const TOKEN_LIFETIME_SECS: i64 = 24 * 60 * 60;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/auth/register", post(register))
+        .route("/auth/login", post(login))
+}
+
+/// JWT 서명/검증 키 (`JWT_SECRET`)
+#[derive(Clone)]
+pub struct JwtKeys {
+    encoding: EncodingKey,
+    decoding: DecodingKey,
+}
+
+impl std::fmt::Debug for JwtKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("JwtKeys(..)")
+    }
+}
+
+/// 토큰 내용
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Claims {
+    /// 사용자 id
+    pub sub: Uuid,
+    pub username: String,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+impl JwtKeys {
+    pub fn new(secret: &[u8]) -> Self {
+        Self {
+            encoding: EncodingKey::from_secret(secret),
+            decoding: DecodingKey::from_secret(secret),
+        }
+    }
+
+    pub fn issue(&self, user_id: Uuid, username: &str, now: DateTime<Utc>) -> Result<(String, Claims), ApiError> {
+        let claims = Claims {
+            sub: user_id,
+            username: username.to_string(),
+            iat: now.timestamp(),
+            exp: now.timestamp() + TOKEN_LIFETIME_SECS,
+        };
+        let token = jsonwebtoken::encode(&Header::default(), &claims, &self.encoding)
+            .map_err(|error| ApiError::Internal(format!("Failed to sign token: {error}")))?;
+        Ok((token, claims))
+    }
+
+    /// 서명과 만료를 확인한 토큰 내용
+    pub fn verify(&self, token: &str) -> Result<Claims, ApiError> {
+        jsonwebtoken::decode::<Claims>(token, &self.decoding, &Validation::default())
+            .map(|data| data.claims)
+            .map_err(|_| ApiError::Unauthorized("Invalid or expired token"))
+    }
+}
+
+pub fn hash_password(password: &str) -> Result<String, ApiError> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|error| ApiError::Internal(format!("Failed to hash password: {error}")))
+}
+
+/// 없는 계정으로 로그인할 때 대신 검증하는 해시.
+/// 있는 계정과 똑같이 argon2를 한 번 돌려 응답 시간으로 계정을 알아낼 수 없게 합니다.
+static DUMMY_PASSWORD_HASH: LazyLock<String> =
+    LazyLock::new(|| hash_password("legend-dummy-password").expect("hashing a fixed password"));
+
+/// 저장된 해시가 깨져 있어도 false
+pub fn verify_password(password: &str, hash: &str) -> bool {
+    PasswordHash::new(hash).is_ok_and(|hash| Argon2::default().verify_password(password.as_bytes(), &hash).is_ok())
+}
+
+/// 인증된 요청의 사용자. 핸들러 인자로 받으면
+/// `Authorization: Bearer <token>`이 없거나 틀린 요청은 401로 거절됩니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthUser {
+    pub id: Uuid,
+    pub username: String,
+}
+
+impl FromRequestParts<AppState> for AuthUser {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .ok_or(ApiError::Unauthorized("Missing bearer token"))?;
+        let claims = state.jwt.verify(token)?;
+
+        // Tokens outlive account changes, so check the account is still usable
+        let is_active: Option<Option<bool>> = sqlx::query_scalar("SELECT is_active FROM users WHERE id = $1")
+            .bind(claims.sub)
+            .fetch_optional(&state.db)
+            .await?;
+        match is_active {
+            None => Err(ApiError::Unauthorized("Account no longer exists")),
+            Some(Some(false)) => Err(ApiError::Forbidden("Account is disabled")),
+            Some(_) => Ok(Self {
+                id: claims.sub,
+                username: claims.username,
+            }),
+        }
+    }
+}
+
+/// argon2는 일부러 느리므로 비동기 작업자 스레드를 막지 않게 따로 돌립니다
+async fn blocking<T: Send + 'static>(work: impl FnOnce() -> T + Send + 'static) -> Result<T, ApiError> {
+    tokio::task::spawn_blocking(work)
+        .await
+        .map_err(|error| ApiError::Internal(format!("Password task failed: {error}")))
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct UserRow {
+    id: Uuid,
+    username: String,
+    email: Option<String>,
+    password_hash: String,
+    is_active: Option<bool>,
+}
+
+impl From<UserRow> for UserResponse {
+    fn from(row: UserRow) -> Self {
+        Self {
+            id: row.id,
+            username: row.username,
+            email: row.email,
+        }
+    }
+}
+
+async fn register(
+    State(state): State<AppState>,
+    Json(request): Json<RegisterRequest>,
+) -> Result<(StatusCode, Json<UserResponse>), ApiError> {
+    request.validate().map_err(|error| ApiError::BadRequest(error.to_string()))?;
+
+    let password = request.password.clone();
+    let password_hash = blocking(move || hash_password(&password)).await??;
+    let user = sqlx::query_as::<_, UserRow>(
+        "INSERT INTO users (username, password_hash, email) VALUES ($1, $2, $3)
+         RETURNING id, username, email, password_hash, is_active",
+    )
+    .bind(&request.username)
+    .bind(&password_hash)
+    .bind(&request.email)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|error| {
+        if is_unique_violation(&error) {
+            ApiError::Conflict("Username is already taken")
+        } else {
+            error.into()
+        }
+    })?;
+
+    tracing::info!(user_id = %user.id, username = %user.username, "Account registered");
+    Ok((StatusCode::CREATED, Json(user.into())))
+}
+
+async fn login(State(state): State<AppState>, Json(request): Json<LoginRequest>) -> Result<Json<LoginResponse>, ApiError> {
+    const INVALID: ApiError = ApiError::Unauthorized("Invalid username or password");
+
+    // No registered password is this long; don't spend argon2 time on huge bodies
+    if request.password.chars().count() > PASSWORD_MAX_LEN {
+        return Err(INVALID);
+    }
+
+    let user = sqlx::query_as::<_, UserRow>(
+        "SELECT id, username, email, password_hash, is_active FROM users WHERE username = $1",
+    )
+    .bind(&request.username)
+    .fetch_optional(&state.db)
+    .await?;
+
+    // Unknown usernames still pay for a hash check, so timing doesn't reveal which accounts exist
+    let hash = user.as_ref().map_or_else(|| DUMMY_PASSWORD_HASH.clone(), |user| user.password_hash.clone());
+    let password = request.password;
+    let verified = blocking(move || verify_password(&password, &hash)).await?;
+    let Some(user) = user.filter(|_| verified) else {
+        return Err(INVALID);
+    };
+    // Checked after the password so the response doesn't reveal which accounts exist
+    if user.is_active == Some(false) {
+        return Err(ApiError::Forbidden("Account is disabled"));
+    }
+
+    let now: DateTime<Utc> = sqlx::query_scalar("UPDATE users SET last_login_at = now() WHERE id = $1 RETURNING last_login_at")
+        .bind(user.id)
+        .fetch_one(&state.db)
+        .await?;
+    let (token, claims) = state.jwt.issue(user.id, &user.username, now)?;
+
+    tracing::info!(user_id = %user.id, "Logged in");
+    Ok(Json(LoginResponse {
+        token,
+        expires_at: claims.exp,
+        user: user.into(),
+    }))
+}
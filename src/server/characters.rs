@@ -0,0 +1,181 @@
+//! Characters - 캐릭터 목록/생성/조회/삭제
+//!
+//! 모든 경로는 로그인이 필요하며, 다른 사용자의 캐릭터는 없는 것처럼 404로 응답합니다.
+
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::server::auth::AuthUser;
+use crate::server::error::{ApiError, is_unique_violation};
+use crate::server::state::AppState;
+use crate::shared::domain::character::{
+    Character, CharacterClass, CreateCharacterRequest, Gender, Stats, validate_character_name,
+};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/characters", get(list_characters).post(create_character))
+        .route("/characters/{id}", get(get_character).delete(delete_character))
+}
+
+/// 컬럼 기본값이 NULL 허용이라 COALESCE로 채워서 읽습니다
+const CHARACTER_COLUMNS: &str = "id, name, class_id, gender,
+    COALESCE(level, 1) AS level, COALESCE(exp, 0) AS exp, COALESCE(total_exp, 0) AS total_exp,
+    COALESCE(hp, 0) AS hp, COALESCE(mp, 0) AS mp,
+    COALESCE(bonus_str_stat, 0) AS bonus_str_stat, COALESCE(bonus_dex_stat, 0) AS bonus_dex_stat,
+    COALESCE(bonus_int_stat, 0) AS bonus_int_stat, COALESCE(bonus_wis_stat, 0) AS bonus_wis_stat,
+    COALESCE(bonus_con_stat, 0) AS bonus_con_stat, COALESCE(stat_points, 0) AS stat_points,
+    COALESCE(gold, 0) AS gold, COALESCE(current_map, '') AS current_map,
+    COALESCE(pos_x, 0) AS pos_x, COALESCE(pos_y, 0) AS pos_y,
+    COALESCE(monsters_killed, 0) AS monsters_killed, COALESCE(deaths, 0) AS deaths,
+    COALESCE(play_time_seconds, 0) AS play_time_seconds,
+    COALESCE(created_at, now()) AS created_at, COALESCE(last_played_at, now()) AS last_played_at";
+
+#[derive(Debug, sqlx::FromRow)]
+struct CharacterRow {
+    id: Uuid,
+    name: String,
+    class_id: Option<i32>,
+    gender: String,
+    level: i32,
+    exp: i64,
+    total_exp: i64,
+    hp: i32,
+    mp: i32,
+    bonus_str_stat: i32,
+    bonus_dex_stat: i32,
+    bonus_int_stat: i32,
+    bonus_wis_stat: i32,
+    bonus_con_stat: i32,
+    stat_points: i32,
+    gold: i64,
+    current_map: String,
+    pos_x: f64,
+    pos_y: f64,
+    monsters_killed: i32,
+    deaths: i32,
+    play_time_seconds: i64,
+    created_at: DateTime<Utc>,
+    last_played_at: DateTime<Utc>,
+}
+
+impl TryFrom<CharacterRow> for Character {
+    type Error = ApiError;
+
+    fn try_from(row: CharacterRow) -> Result<Self, Self::Error> {
+        let class = row
+            .class_id
+            .and_then(CharacterClass::from_id)
+            .ok_or_else(|| ApiError::Internal(format!("Character {} has unknown class {:?}", row.id, row.class_id)))?;
+        let gender = Gender::parse(&row.gender)
+            .ok_or_else(|| ApiError::Internal(format!("Character {} has unknown gender '{}'", row.id, row.gender)))?;
+        Ok(Self {
+            id: row.id,
+            name: row.name,
+            class,
+            gender,
+            level: row.level,
+            exp: row.exp,
+            total_exp: row.total_exp,
+            hp: row.hp,
+            mp: row.mp,
+            bonus_stats: Stats {
+                str: row.bonus_str_stat,
+                dex: row.bonus_dex_stat,
+                int: row.bonus_int_stat,
+                wis: row.bonus_wis_stat,
+                con: row.bonus_con_stat,
+            },
+            stat_points: row.stat_points,
+            gold: row.gold,
+            current_map: row.current_map,
+            pos_x: row.pos_x,
+            pos_y: row.pos_y,
+            monsters_killed: row.monsters_killed,
+            deaths: row.deaths,
+            play_time_seconds: row.play_time_seconds,
+            created_at: row.created_at,
+            last_played_at: row.last_played_at,
+        })
+    }
+}
+
+async fn list_characters(State(state): State<AppState>, user: AuthUser) -> Result<Json<Vec<Character>>, ApiError> {
+    let rows = sqlx::query_as::<_, CharacterRow>(&format!(
+        "SELECT {CHARACTER_COLUMNS} FROM characters WHERE user_id = $1 ORDER BY created_at, name"
+    ))
+    .bind(user.id)
+    .fetch_all(&state.db)
+    .await?;
+    let characters = rows.into_iter().map(Character::try_from).collect::<Result<_, _>>()?;
+    Ok(Json(characters))
+}
+
+async fn create_character(
+    State(state): State<AppState>,
+    user: AuthUser,
+    request: Result<Json<CreateCharacterRequest>, JsonRejection>,
+) -> Result<(StatusCode, Json<Character>), ApiError> {
+    // Unknown classes/genders fail here; answer 400 like other validation errors
+    let Json(request) = request.map_err(|rejection| ApiError::BadRequest(rejection.body_text()))?;
+    validate_character_name(&request.name).map_err(|error| ApiError::BadRequest(error.to_string()))?;
+
+    let row = sqlx::query_as::<_, CharacterRow>(&format!(
+        "INSERT INTO characters (user_id, name, class_id, gender) VALUES ($1, $2, $3, $4)
+         RETURNING {CHARACTER_COLUMNS}"
+    ))
+    .bind(user.id)
+    .bind(&request.name)
+    .bind(request.class.id())
+    .bind(request.gender.as_str())
+    .fetch_one(&state.db)
+    .await
+    .map_err(|error| {
+        if is_unique_violation(&error) {
+            ApiError::Conflict("Character name is already taken")
+        } else {
+            error.into()
+        }
+    })?;
+
+    let character = Character::try_from(row)?;
+    tracing::info!(user_id = %user.id, character_id = %character.id, name = %character.name, "Character created");
+    Ok((StatusCode::CREATED, Json(character)))
+}
+
+async fn get_character(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Character>, ApiError> {
+    let row = sqlx::query_as::<_, CharacterRow>(&format!(
+        "SELECT {CHARACTER_COLUMNS} FROM characters WHERE id = $1 AND user_id = $2"
+    ))
+    .bind(id)
+    .bind(user.id)
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or(ApiError::NotFound("Character not found"))?;
+    Ok(Json(row.try_into()?))
+}
+
+async fn delete_character(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    let result = sqlx::query("DELETE FROM characters WHERE id = $1 AND user_id = $2")
+        .bind(id)
+        .bind(user.id)
+        .execute(&state.db)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(ApiError::NotFound("Character not found"));
+    }
+    tracing::info!(user_id = %user.id, character_id = %id, "Character deleted");
+    Ok(StatusCode::NO_CONTENT)
+}
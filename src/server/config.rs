@@ -0,0 +1,36 @@
+//! Server Config - 환경 변수 설정
+
+use std::net::SocketAddr;
+use crate::server::error::ServerError;
+
+/// `API_ADDR`가 없을 때 (compose.yml의 3000 포트)
+pub const DEFAULT_BIND_ADDR: &str = "0.0.0.0:3000";
+
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    /// `DATABASE_URL` (필수)
+    pub database_url: String,
+    /// `API_ADDR`
+    pub bind_addr: SocketAddr,
+    /// `JWT_SECRET` (필수), 로그인 토큰 서명 키
+    pub jwt_secret: String,
+}
+
+impl ServerConfig {
+    pub fn from_env() -> Result<Self, ServerError> {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    /// 환경 변수 대신 임의의 조회 함수로 읽기
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, ServerError> {
+        let database_url = lookup("DATABASE_URL")
+            .filter(|url| !url.is_empty())
+            .ok_or(ServerError::MissingEnv("DATABASE_URL"))?;
+        let addr = lookup("API_ADDR").unwrap_or_else(|| DEFAULT_BIND_ADDR.to_string());
+        let bind_addr = addr.parse().map_err(|_| ServerError::InvalidEnv { key: "API_ADDR", value: addr })?;
+        let jwt_secret = lookup("JWT_SECRET")
+            .filter(|secret| !secret.is_empty())
+            .ok_or(ServerError::MissingEnv("JWT_SECRET"))?;
+        Ok(Self { database_url, bind_addr, jwt_secret })
+    }
+}
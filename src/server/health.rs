@@ -0,0 +1,38 @@
+//! Health Check - 상태 확인
+//!
+//! `GET /api/health`: DB에 닿으면 200, 아니면 503.
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use crate::server::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/health", get(health))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthResponse {
+    /// `ok` 또는 `degraded`
+    pub status: String,
+    /// `ok` 또는 `unavailable`
+    pub database: String,
+    pub version: String,
+}
+
+async fn health(State(state): State<AppState>) -> (StatusCode, Json<HealthResponse>) {
+    let database_ok = sqlx::query("SELECT 1").execute(&state.db).await.is_ok();
+    let (code, status, database) = if database_ok {
+        (StatusCode::OK, "ok", "ok")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "degraded", "unavailable")
+    };
+    let response = HealthResponse {
+        status: status.to_string(),
+        database: database.to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+    };
+    (code, Json(response))
+}
@@ -0,0 +1,87 @@
+//! API Server - REST API 서버
+//!
+//! `legend-api` 바이너리 (`--features server`).
+//! 모든 경로는 `/api` 아래에 있으며, Trunk 개발 서버가 `/api`를 이 서버로 프록시합니다.
+
+pub mod auth;
+pub mod characters;
+pub mod config;
+pub mod error;
+pub mod health;
+pub mod state;
+
+use axum::Router;
+use sqlx::postgres::PgPoolOptions;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tower_http::trace::TraceLayer;
+use tracing::info;
+
+pub use config::ServerConfig;
+pub use error::{ApiError, ServerError};
+pub use state::AppState;
+
+/// DB 커넥션 풀 최대 크기
+const MAX_DB_CONNECTIONS: u32 = 10;
+
+/// 커넥션을 기다리는 최대 시간
+const DB_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 전체 라우터
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .nest("/api", health::routes().merge(auth::routes()).merge(characters::routes()))
+        .layer(TraceLayer::new_for_http())
+        .with_state(state)
+}
+
+/// DB 연결, 마이그레이션 후 종료 신호가 올 때까지 요청 처리
+pub async fn serve(config: ServerConfig) -> Result<(), ServerError> {
+    let db = PgPoolOptions::new()
+        .max_connections(MAX_DB_CONNECTIONS)
+        .acquire_timeout(DB_ACQUIRE_TIMEOUT)
+        .connect(&config.database_url)
+        .await?;
+    sqlx::migrate!("./migrations").run(&db).await?;
+
+    let listener = TcpListener::bind(config.bind_addr).await?;
+    info!(addr = %config.bind_addr, "API server listening");
+    axum::serve(listener, router(AppState::new(db.clone(), config.jwt_secret.as_bytes())))
+        .with_graceful_shutdown(shutdown_signal())
+        .await?;
+
+    db.close().await;
+    info!("API server stopped");
+    Ok(())
+}
+
+/// Ctrl+C 또는 SIGTERM (`docker compose stop`)
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(error) = tokio::signal::ctrl_c().await {
+            tracing::error!(%error, "Failed to listen for Ctrl+C");
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(error) => {
+                tracing::error!(%error, "Failed to listen for SIGTERM");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+    info!("Shutdown signal received, draining connections");
+}
@@ -0,0 +1,19 @@
+//! App State - 핸들러 공용 상태
+
+use sqlx::PgPool;
+use crate::server::auth::JwtKeys;
+
+#[derive(Debug, Clone)]
+pub struct AppState {
+    pub db: PgPool,
+    pub jwt: JwtKeys,
+}
+
+impl AppState {
+    pub fn new(db: PgPool, jwt_secret: &[u8]) -> Self {
+        Self {
+            db,
+            jwt: JwtKeys::new(jwt_secret),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use legend_client::server::{self, ServerConfig};
+use tracing_subscriber::EnvFilter;
+
+#[tokio::main]
+async fn main() {
+    init_logging();
+
+    let result = match ServerConfig::from_env() {
+        Ok(config) => server::serve(config).await,
+        Err(error) => Err(error),
+    };
+    if let Err(error) = result {
+        tracing::error!(%error, "API server failed");
+        std::process::exit(1);
+    }
+}
+
+/// `RUST_LOG` filters (default `info`); `LOG_FORMAT=json` prints one JSON object per line
+fn init_logging() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let builder = tracing_subscriber::fmt().with_env_filter(filter);
+    if std::env::var("LOG_FORMAT").is_ok_and(|format| format == "json") {
+        builder.json().init();
+    } else {
+        builder.init();
+    }
+}
@@ -8,9 +8,13 @@
 // ============================================================
 
 /// 타일 너비 (픽셀) - Isometric 2:1 ratio 기준
+///
+/// 게임 월드에서 타일은 XZ 평면의 정사각형(`TILE_WORLD_SIZE`)이고,
+/// 화면의 마름모 모양은 기울어진 카메라가 만듭니다.
+/// 그래서 월드 크기에는 너비(`GRID_UNIT`)만 쓰이고 높이 32px는 2D 에셋 규격으로만 남습니다.
 pub const TILE_WIDTH: f32 = 64.0;
 
-/// 타일 높이 (픽셀) - Isometric 2:1 ratio 기준
+/// 타일 높이 (픽셀) - Isometric 2:1 ratio 기준 (화면상 높이, 월드 크기에는 쓰이지 않음)
 pub const TILE_HEIGHT: f32 = 32.0;
 
 /// 게임 월드의 그리드 단위 (논리적 그리드 1칸 = 실제 픽셀)
@@ -39,6 +43,14 @@ pub const CHARACTER_RENDER_WIDTH: f32 = CHARACTER_SPRITE_WIDTH * CHARACTER_RENDE
 /// 캐릭터 실제 렌더링 높이 (스케일 적용 후)
 pub const CHARACTER_RENDER_HEIGHT: f32 = CHARACTER_SPRITE_HEIGHT * CHARACTER_RENDER_SCALE;
 
+/// 월드 1유닛당 픽셀 수 (3D 월드 변환 기준)
+/// 64px 캐릭터 = 월드 2유닛
+pub const PIXELS_PER_WORLD_UNIT: f32 = 32.0;
+
+/// 타일 1칸의 월드 크기 (월드 유닛), 정사각형 한 변
+/// GRID_UNIT 64px = 월드 2유닛
+pub const TILE_WORLD_SIZE: f32 = GRID_UNIT / PIXELS_PER_WORLD_UNIT;
+
 // ============================================================
 // 👾 3. 몬스터 규격 (Monster Specifications)
 // ============================================================
@@ -89,11 +101,11 @@ pub const ANIMATION_FRAMES_DEFAULT: usize = 4;
 // 🌍 6. 맵 규격 (Map Specifications)
 // ============================================================
 
-/// 기본 맵 너비 (그리드 단위)
-pub const MAP_WIDTH_DEFAULT: i32 = 16;
+/// 기본 맵 너비 (타일 단위), 맵 파일이 `width`를 생략하면 사용
+pub const MAP_WIDTH_DEFAULT: u32 = 16;
 
-/// 기본 맵 높이 (그리드 단위)
-pub const MAP_HEIGHT_DEFAULT: i32 = 16;
+/// 기본 맵 높이 (타일 단위), 맵 파일이 `height`를 생략하면 사용
+pub const MAP_HEIGHT_DEFAULT: u32 = 16;
 
 // ============================================================
 // 📊 7. Z-Index / 레이어 순서 (Rendering Layers)
@@ -0,0 +1,83 @@
+//! Character Data - 클래스 정의와 경험치 테이블
+//!
+//! 경험치 공식 (ASSETS.md): `exp_to_next = floor(100 × level^1.5)`, 만렙 99.
+
+use crate::shared::domain::character::{CharacterClass, Gender, MAX_LEVEL, Stats};
+
+/// 서클 하나가 차지하는 레벨 수 (1서클 1-20, ..., 5서클 81-99)
+pub const LEVELS_PER_CIRCLE: i32 = 20;
+
+/// 클래스 정의
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClassDef {
+    pub class: CharacterClass,
+    pub name_ko: &'static str,
+    pub description: &'static str,
+    /// 레벨 1 기본 능력치 (`characters.bonus_*`는 이 위에 더해짐)
+    pub base_stats: Stats,
+    pub base_hp: i32,
+    pub base_mp: i32,
+    /// 레벨업마다 오르는 최대 HP/MP
+    pub hp_per_level: i32,
+    pub mp_per_level: i32,
+    /// 성별 폴더가 들어 있는 스프라이트 폴더 (assets 기준)
+    pub sprite_dir: &'static str,
+}
+
+impl ClassDef {
+    /// 4×4 캐릭터 시트 경로
+    pub fn sprite(&self, gender: Gender) -> String {
+        format!("{}/{}/spritesheet.png", self.sprite_dir, gender.as_str())
+    }
+
+    pub fn max_hp(&self, level: i32) -> i32 {
+        self.base_hp + self.hp_per_level * (level.clamp(1, MAX_LEVEL) - 1)
+    }
+
+    pub fn max_mp(&self, level: i32) -> i32 {
+        self.base_mp + self.mp_per_level * (level.clamp(1, MAX_LEVEL) - 1)
+    }
+}
+
+pub const CLASSES: &[ClassDef] = &[
+    ClassDef { class: CharacterClass::Warrior, name_ko: "전사", description: "두꺼운 갑옷과 검으로 앞에서 버티는 근접 전투가", base_stats: Stats::new(14, 8, 4, 5, 12), base_hp: 120, base_mp: 30, hp_per_level: 12, mp_per_level: 3, sprite_dir: "characters/warrior" },
+    ClassDef { class: CharacterClass::Rogue, name_ko: "도적", description: "그림자에 숨어 급소를 노리는 암살자", base_stats: Stats::new(9, 14, 5, 5, 9), base_hp: 100, base_mp: 40, hp_per_level: 9, mp_per_level: 4, sprite_dir: "characters/rogue" },
+    ClassDef { class: CharacterClass::Mage, name_ko: "마법사", description: "불, 얼음, 번개를 다루는 원거리 마법사", base_stats: Stats::new(4, 7, 15, 11, 6), base_hp: 80, base_mp: 80, hp_per_level: 6, mp_per_level: 9, sprite_dir: "characters/mage" },
+    ClassDef { class: CharacterClass::Cleric, name_ko: "성직자", description: "신성 마법으로 아군을 치유하고 지키는 사제", base_stats: Stats::new(7, 6, 9, 14, 9), base_hp: 95, base_mp: 70, hp_per_level: 8, mp_per_level: 8, sprite_dir: "characters/cleric" },
+    ClassDef { class: CharacterClass::MartialArtist, name_ko: "무도가", description: "맨손과 기(氣)로 싸우는 격투가", base_stats: Stats::new(12, 12, 4, 6, 10), base_hp: 110, base_mp: 40, hp_per_level: 11, mp_per_level: 4, sprite_dir: "characters/martial_artist" },
+];
+
+pub fn class_def(class: CharacterClass) -> &'static ClassDef {
+    CLASSES.iter().find(|def| def.class == class).expect("every class has a ClassDef")
+}
+
+/// 다음 레벨까지 필요한 경험치. 만렙이면 None.
+pub fn exp_to_next(level: i32) -> Option<i64> {
+    if !(1..MAX_LEVEL).contains(&level) {
+        return None;
+    }
+    Some((100.0 * (level as f64).powf(1.5)).floor() as i64)
+}
+
+/// 레벨 1에서 `level`까지 필요한 누적 경험치
+pub fn total_exp_for_level(level: i32) -> i64 {
+    (1..level.clamp(1, MAX_LEVEL)).filter_map(exp_to_next).sum()
+}
+
+/// 누적 경험치로 도달하는 레벨
+pub fn level_for_total_exp(total_exp: i64) -> i32 {
+    let mut level = 1;
+    let mut remaining = total_exp;
+    while let Some(needed) = exp_to_next(level)
+        && remaining >= needed
+    {
+        remaining -= needed;
+        level += 1;
+    }
+    level
+}
+
+/// 레벨이 속한 서클 (1-5)
+pub fn circle_for_level(level: i32) -> u8 {
+    ((level.clamp(1, MAX_LEVEL) - 1) / LEVELS_PER_CIRCLE + 1).min(5) as u8
+}
@@ -0,0 +1,103 @@
+//! Item Definitions - 아이템 정의
+//!
+//! `public/assets/items/`에 아이콘이 있는 아이템들.
+//! 몸에 그려지는 장비는 `sprite`로 Paper Doll 레이어 시트를 가리킵니다.
+//! id는 종류별 백의 자리 (1 무기, 2 갑옷, 3 장신구, 4 소모품, 5 기타), `character_inventory.item_id`.
+
+use crate::shared::domain::character::{CharacterClass, Stats};
+use crate::shared::domain::inventory::EquipSlot;
+
+/// 아이템 분류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemKind {
+    Weapon,
+    Armor,
+    Accessory,
+    /// 사용하면 사라짐 (물약, 주문서)
+    Consumable,
+    /// 제작/강화 재료
+    Material,
+    Currency,
+    /// 팔 수 없는 퀘스트 아이템
+    Quest,
+}
+
+/// 아이템 정의
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ItemDef {
+    pub id: i32,
+    /// 영문 식별자 (`iron_sword`)
+    pub key: &'static str,
+    pub name: &'static str,
+    pub kind: ItemKind,
+    /// 장착 부위, 장비가 아니면 None
+    pub slot: Option<EquipSlot>,
+    pub required_level: i32,
+    /// 착용 가능 클래스, 비어 있으면 모두
+    pub classes: &'static [CharacterClass],
+    pub attack: i32,
+    pub defense: i32,
+    /// 장착 시 능력치
+    pub bonus: Stats,
+    /// 사용 시 회복량
+    pub heal_hp: i32,
+    pub heal_mp: i32,
+    /// 상점 판매가 (골드)
+    pub price: i64,
+    /// 한 칸에 겹치는 최대 수량
+    pub max_stack: i32,
+    /// 64×64 아이콘 (assets 기준)
+    pub icon: &'static str,
+    /// Paper Doll 시트 (`equipment/...`)
+    pub sprite: Option<&'static str>,
+}
+
+impl ItemDef {
+    pub fn is_equipment(&self) -> bool {
+        self.slot.is_some()
+    }
+
+    pub fn usable_by(&self, class: CharacterClass) -> bool {
+        self.classes.is_empty() || self.classes.contains(&class)
+    }
+}
+
+pub const ITEMS: &[ItemDef] = &[
+    // Weapons
+    ItemDef { id: 101, key: "wooden_sword", name: "Wooden Sword", kind: ItemKind::Weapon, slot: Some(EquipSlot::Weapon), required_level: 1, classes: &[CharacterClass::Warrior], attack: 6, defense: 0, bonus: Stats::ZERO, heal_hp: 0, heal_mp: 0, price: 20, max_stack: 1, icon: "items/wooden_sword.png", sprite: Some("equipment/weapons/wooden_sword.png") },
+    ItemDef { id: 102, key: "iron_sword", name: "Iron Sword", kind: ItemKind::Weapon, slot: Some(EquipSlot::Weapon), required_level: 10, classes: &[CharacterClass::Warrior], attack: 14, defense: 0, bonus: Stats::new(1, 0, 0, 0, 0), heal_hp: 0, heal_mp: 0, price: 150, max_stack: 1, icon: "items/iron_sword.png", sprite: Some("equipment/weapons/iron_sword.png") },
+    ItemDef { id: 103, key: "steel_sword", name: "Steel Sword", kind: ItemKind::Weapon, slot: Some(EquipSlot::Weapon), required_level: 25, classes: &[CharacterClass::Warrior], attack: 26, defense: 0, bonus: Stats::new(2, 0, 0, 0, 1), heal_hp: 0, heal_mp: 0, price: 600, max_stack: 1, icon: "items/steel_sword.png", sprite: Some("equipment/weapons/steel_sword.png") },
+    ItemDef { id: 104, key: "knight_sword", name: "Knight Sword", kind: ItemKind::Weapon, slot: Some(EquipSlot::Weapon), required_level: 45, classes: &[CharacterClass::Warrior], attack: 42, defense: 0, bonus: Stats::new(4, 0, 0, 0, 2), heal_hp: 0, heal_mp: 0, price: 2400, max_stack: 1, icon: "items/sword.png", sprite: None },
+    ItemDef { id: 105, key: "rusty_dagger", name: "Rusty Dagger", kind: ItemKind::Weapon, slot: Some(EquipSlot::Weapon), required_level: 1, classes: &[CharacterClass::Rogue], attack: 5, defense: 0, bonus: Stats::new(0, 1, 0, 0, 0), heal_hp: 0, heal_mp: 0, price: 15, max_stack: 1, icon: "items/rusty_dagger.png", sprite: Some("equipment/weapons/rusty_dagger.png") },
+    ItemDef { id: 106, key: "iron_dagger", name: "Iron Dagger", kind: ItemKind::Weapon, slot: Some(EquipSlot::Weapon), required_level: 10, classes: &[CharacterClass::Rogue], attack: 12, defense: 0, bonus: Stats::new(0, 2, 0, 0, 0), heal_hp: 0, heal_mp: 0, price: 140, max_stack: 1, icon: "items/iron_dagger.png", sprite: Some("equipment/weapons/iron_dagger.png") },
+    ItemDef { id: 107, key: "wooden_staff", name: "Wooden Staff", kind: ItemKind::Weapon, slot: Some(EquipSlot::Weapon), required_level: 1, classes: &[CharacterClass::Mage, CharacterClass::Cleric], attack: 4, defense: 0, bonus: Stats::new(0, 0, 1, 1, 0), heal_hp: 0, heal_mp: 0, price: 20, max_stack: 1, icon: "items/wooden_staff.png", sprite: Some("equipment/weapons/wooden_staff.png") },
+    ItemDef { id: 108, key: "magic_staff", name: "Magic Staff", kind: ItemKind::Weapon, slot: Some(EquipSlot::Weapon), required_level: 15, classes: &[CharacterClass::Mage, CharacterClass::Cleric], attack: 10, defense: 0, bonus: Stats::new(0, 0, 3, 2, 0), heal_hp: 0, heal_mp: 0, price: 320, max_stack: 1, icon: "items/magic_staff.png", sprite: Some("equipment/weapons/magic_staff.png") },
+    // Armor
+    ItemDef { id: 201, key: "leather_armor", name: "Leather Armor", kind: ItemKind::Armor, slot: Some(EquipSlot::Armor), required_level: 1, classes: &[], attack: 0, defense: 5, bonus: Stats::ZERO, heal_hp: 0, heal_mp: 0, price: 40, max_stack: 1, icon: "items/leather_armor.png", sprite: Some("equipment/armor/leather_armor.png") },
+    // Accessories
+    ItemDef { id: 301, key: "leather_gloves", name: "Leather Gloves", kind: ItemKind::Accessory, slot: Some(EquipSlot::Gloves), required_level: 5, classes: &[], attack: 1, defense: 2, bonus: Stats::new(0, 1, 0, 0, 0), heal_hp: 0, heal_mp: 0, price: 60, max_stack: 1, icon: "items/gloves.png", sprite: None },
+    ItemDef { id: 302, key: "leather_boots", name: "Leather Boots", kind: ItemKind::Accessory, slot: Some(EquipSlot::Boots), required_level: 5, classes: &[], attack: 0, defense: 2, bonus: Stats::new(0, 1, 0, 0, 0), heal_hp: 0, heal_mp: 0, price: 60, max_stack: 1, icon: "items/boots.png", sprite: None },
+    ItemDef { id: 303, key: "leather_belt", name: "Leather Belt", kind: ItemKind::Accessory, slot: Some(EquipSlot::Belt), required_level: 5, classes: &[], attack: 0, defense: 1, bonus: Stats::new(0, 0, 0, 0, 1), heal_hp: 0, heal_mp: 0, price: 50, max_stack: 1, icon: "items/belt.png", sprite: None },
+    ItemDef { id: 304, key: "silver_ring", name: "Silver Ring", kind: ItemKind::Accessory, slot: Some(EquipSlot::Ring), required_level: 15, classes: &[], attack: 0, defense: 0, bonus: Stats::new(1, 1, 1, 1, 1), heal_hp: 0, heal_mp: 0, price: 500, max_stack: 1, icon: "items/ring.png", sprite: None },
+    ItemDef { id: 305, key: "jade_amulet", name: "Jade Amulet", kind: ItemKind::Accessory, slot: Some(EquipSlot::Amulet), required_level: 20, classes: &[], attack: 0, defense: 0, bonus: Stats::new(0, 0, 2, 2, 0), heal_hp: 0, heal_mp: 0, price: 700, max_stack: 1, icon: "items/amulet.png", sprite: None },
+    // Consumables
+    ItemDef { id: 401, key: "red_potion", name: "Red Potion", kind: ItemKind::Consumable, slot: None, required_level: 1, classes: &[], attack: 0, defense: 0, bonus: Stats::ZERO, heal_hp: 50, heal_mp: 0, price: 10, max_stack: 99, icon: "items/red_potion.png", sprite: None },
+    ItemDef { id: 402, key: "health_potion", name: "Health Potion", kind: ItemKind::Consumable, slot: None, required_level: 15, classes: &[], attack: 0, defense: 0, bonus: Stats::ZERO, heal_hp: 150, heal_mp: 0, price: 40, max_stack: 99, icon: "items/health_potion.png", sprite: None },
+    ItemDef { id: 403, key: "large_red_potion", name: "Large Red Potion", kind: ItemKind::Consumable, slot: None, required_level: 40, classes: &[], attack: 0, defense: 0, bonus: Stats::ZERO, heal_hp: 400, heal_mp: 0, price: 120, max_stack: 99, icon: "items/large_red_potion.png", sprite: None },
+    ItemDef { id: 404, key: "blue_potion", name: "Blue Potion", kind: ItemKind::Consumable, slot: None, required_level: 1, classes: &[], attack: 0, defense: 0, bonus: Stats::ZERO, heal_hp: 0, heal_mp: 30, price: 12, max_stack: 99, icon: "items/blue_potion.png", sprite: None },
+    ItemDef { id: 405, key: "mana_potion", name: "Mana Potion", kind: ItemKind::Consumable, slot: None, required_level: 15, classes: &[], attack: 0, defense: 0, bonus: Stats::ZERO, heal_hp: 0, heal_mp: 100, price: 45, max_stack: 99, icon: "items/mana_potion.png", sprite: None },
+    ItemDef { id: 406, key: "return_scroll", name: "Scroll of Return", kind: ItemKind::Consumable, slot: None, required_level: 1, classes: &[], attack: 0, defense: 0, bonus: Stats::ZERO, heal_hp: 0, heal_mp: 0, price: 30, max_stack: 20, icon: "items/scroll.png", sprite: None },
+    // Misc
+    ItemDef { id: 501, key: "gold_coin", name: "Gold Coin", kind: ItemKind::Currency, slot: None, required_level: 1, classes: &[], attack: 0, defense: 0, bonus: Stats::ZERO, heal_hp: 0, heal_mp: 0, price: 1, max_stack: 9999, icon: "items/gold_coin.png", sprite: None },
+    ItemDef { id: 502, key: "gem", name: "Gem", kind: ItemKind::Material, slot: None, required_level: 1, classes: &[], attack: 0, defense: 0, bonus: Stats::ZERO, heal_hp: 0, heal_mp: 0, price: 250, max_stack: 99, icon: "items/gem.png", sprite: None },
+    ItemDef { id: 503, key: "dungeon_key", name: "Dungeon Key", kind: ItemKind::Quest, slot: None, required_level: 1, classes: &[], attack: 0, defense: 0, bonus: Stats::ZERO, heal_hp: 0, heal_mp: 0, price: 0, max_stack: 10, icon: "items/key.png", sprite: None },
+];
+
+/// id로 아이템 정의 조회
+pub fn item_by_id(id: i32) -> Option<&'static ItemDef> {
+    ITEMS.iter().find(|item| item.id == id)
+}
+
+pub fn item_by_key(key: &str) -> Option<&'static ItemDef> {
+    ITEMS.iter().find(|item| item.key == key)
+}
@@ -0,0 +1,91 @@
+//! Map Definitions - 맵/던전 정의
+//!
+//! 서클당 3개 (마을, 사냥터, 던전), 총 15개.
+//! `file`이 있는 맵은 `public/assets/maps/`의 맵 파일로 그려집니다.
+
+/// 맵 분류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MapKind {
+    /// 상점과 NPC가 있는 안전 지대
+    Town,
+    /// 몬스터가 계속 나오는 사냥터
+    Field,
+    /// 보스가 있는 던전 ([`DungeonDef`])
+    Dungeon,
+}
+
+/// 맵 정의
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapDef {
+    /// `MapFile.id`, `characters.current_map`
+    pub id: &'static str,
+    pub name: &'static str,
+    pub circle: u8,
+    pub kind: MapKind,
+    /// 권장 레벨 범위
+    pub min_level: i32,
+    pub max_level: i32,
+    /// 나오는 몬스터 (`shared::data::monsters` id)
+    pub monsters: &'static [&'static str],
+    /// 맵 파일 경로 (assets 기준), 아직 없으면 None
+    pub file: Option<&'static str>,
+}
+
+pub const MAPS: &[MapDef] = &[
+    // 1서클
+    MapDef { id: "village_milles", name: "밀레스 마을", circle: 1, kind: MapKind::Town, min_level: 1, max_level: 20, monsters: &[], file: Some("maps/village_milles.json") },
+    MapDef { id: "milles_plains", name: "밀레스 평원", circle: 1, kind: MapKind::Field, min_level: 1, max_level: 20, monsters: &["rat", "slime", "fox", "wolf"], file: Some("maps/milles_plains.json") },
+    MapDef { id: "wolf_forest", name: "늑대숲", circle: 1, kind: MapKind::Dungeon, min_level: 10, max_level: 20, monsters: &["fox", "wolf", "wolf_chief"], file: None },
+    // 2서클
+    MapDef { id: "sarak_oasis", name: "사라크 오아시스", circle: 2, kind: MapKind::Town, min_level: 21, max_level: 40, monsters: &[], file: None },
+    MapDef { id: "sarak_desert", name: "사라크 사막", circle: 2, kind: MapKind::Field, min_level: 21, max_level: 40, monsters: &["bat", "goblin", "spider"], file: None },
+    MapDef { id: "pyramid", name: "피라미드", circle: 2, kind: MapKind::Dungeon, min_level: 30, max_level: 40, monsters: &["bat", "spider", "skeleton", "scorpion_king"], file: None },
+    // 3서클
+    MapDef { id: "frost_haven", name: "프로스트 헤이븐", circle: 3, kind: MapKind::Town, min_level: 41, max_level: 60, monsters: &[], file: None },
+    MapDef { id: "frost_iceberg", name: "빙산", circle: 3, kind: MapKind::Field, min_level: 41, max_level: 60, monsters: &["zombie", "ghost", "troll"], file: None },
+    MapDef { id: "ice_cave", name: "얼음동굴", circle: 3, kind: MapKind::Dungeon, min_level: 50, max_level: 60, monsters: &["ghost", "troll", "golem", "ice_golem"], file: None },
+    // 4서클
+    MapDef { id: "ember_outpost", name: "엠버 전초기지", circle: 4, kind: MapKind::Town, min_level: 61, max_level: 80, monsters: &[], file: None },
+    MapDef { id: "ember_volcano", name: "화산", circle: 4, kind: MapKind::Field, min_level: 61, max_level: 80, monsters: &["orc", "wraith", "demon"], file: None },
+    MapDef { id: "demon_lair", name: "악마소굴", circle: 4, kind: MapKind::Dungeon, min_level: 70, max_level: 80, monsters: &["demon", "wraith", "demon_lord", "flame_lord"], file: None },
+    // 5서클
+    MapDef { id: "shadow_sanctuary", name: "그림자 성역", circle: 5, kind: MapKind::Town, min_level: 81, max_level: 99, monsters: &[], file: None },
+    MapDef { id: "dragon_nest", name: "용의 둥지", circle: 5, kind: MapKind::Field, min_level: 81, max_level: 99, monsters: &["dark_knight", "lich", "dragon"], file: None },
+    MapDef { id: "dark_castle", name: "어둠의 성", circle: 5, kind: MapKind::Dungeon, min_level: 90, max_level: 99, monsters: &["dark_knight", "lich", "arclich", "dark_lord"], file: None },
+];
+
+/// 던전 정의 (`character_dungeon_progress.dungeon_id`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DungeonDef {
+    pub id: i32,
+    /// 던전 맵 id ([`MapDef`])
+    pub map: &'static str,
+    pub name: &'static str,
+    pub required_level: i32,
+    /// 잡으면 클리어되는 몬스터
+    pub boss: &'static str,
+    /// 제한 시간 (초)
+    pub time_limit_secs: u32,
+}
+
+pub const DUNGEONS: &[DungeonDef] = &[
+    DungeonDef { id: 1, map: "wolf_forest", name: "늑대숲", required_level: 10, boss: "wolf_chief", time_limit_secs: 600 },
+    DungeonDef { id: 2, map: "pyramid", name: "피라미드", required_level: 30, boss: "scorpion_king", time_limit_secs: 900 },
+    DungeonDef { id: 3, map: "ice_cave", name: "얼음동굴", required_level: 50, boss: "ice_golem", time_limit_secs: 900 },
+    DungeonDef { id: 4, map: "demon_lair", name: "악마소굴", required_level: 70, boss: "flame_lord", time_limit_secs: 1200 },
+    DungeonDef { id: 5, map: "dark_castle", name: "어둠의 성", required_level: 90, boss: "dark_lord", time_limit_secs: 1800 },
+];
+
+/// id로 맵 정의 조회
+pub fn map_by_id(id: &str) -> Option<&'static MapDef> {
+    MAPS.iter().find(|map| map.id == id)
+}
+
+pub fn dungeon_by_id(id: i32) -> Option<&'static DungeonDef> {
+    DUNGEONS.iter().find(|dungeon| dungeon.id == id)
+}
+
+/// 서클의 맵 (마을, 사냥터, 던전 순)
+pub fn maps_in_circle(circle: u8) -> impl Iterator<Item = &'static MapDef> {
+    MAPS.iter().filter(move |map| map.circle == circle)
+}
@@ -0,0 +1,10 @@
+//! Static Game Data - 정적 게임 데이터
+//!
+//! 밸런스 데이터는 DB가 아닌 const 테이블로 관리합니다.
+//! DB에는 이 테이블의 id만 저장됩니다.
+
+pub mod characters;
+pub mod items;
+pub mod maps;
+pub mod monsters;
+pub mod skills;
@@ -0,0 +1,103 @@
+//! Monster Definitions - 몬스터 정의
+//!
+//! 서클당 5종, 총 25종. 서클 던전 보스 5종(`wolf_chief` 등)은
+//! `public/assets/monsters/`의 기존 스프라이트를 다시 씁니다.
+//! 테이블은 크기별로, 크기 안에서는 체력이 낮은 순서로 정렬되어 있습니다.
+
+use crate::shared::constants::{
+    MONSTER_RENDER_SCALE, MONSTER_SPRITE_BOSS, MONSTER_SPRITE_LARGE, MONSTER_SPRITE_MEDIUM,
+    MONSTER_SPRITE_SMALL, PIXELS_PER_WORLD_UNIT,
+};
+
+/// 몬스터 크기 분류 (ASSETS.md 몬스터 규격)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MonsterSize {
+    Small,
+    Medium,
+    Large,
+    Boss,
+}
+
+impl MonsterSize {
+    /// 프레임 크기 (픽셀)
+    pub const fn sprite_size(self) -> f32 {
+        match self {
+            MonsterSize::Small => MONSTER_SPRITE_SMALL,
+            MonsterSize::Medium => MONSTER_SPRITE_MEDIUM,
+            MonsterSize::Large => MONSTER_SPRITE_LARGE,
+            MonsterSize::Boss => MONSTER_SPRITE_BOSS,
+        }
+    }
+
+    /// 월드 렌더링 크기 (월드 유닛)
+    pub fn world_size(self) -> f32 {
+        self.sprite_size() * MONSTER_RENDER_SCALE / PIXELS_PER_WORLD_UNIT
+    }
+}
+
+/// 몬스터 정의
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonsterDef {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub size: MonsterSize,
+    /// 출현 서클 (1-5)
+    pub circle: u8,
+    pub hp: u32,
+    pub damage: u32,
+    /// 이동 속도 (초당 월드 유닛)
+    pub speed: f32,
+    pub xp: u32,
+    /// 스프라이트시트 경로 (assets 기준)
+    pub sprite: &'static str,
+    /// 스프라이트 매니페스트 경로. 없으면 표준 4x4 레이아웃을 사용합니다.
+    pub manifest: Option<&'static str>,
+}
+
+impl MonsterDef {
+    pub fn is_boss(&self) -> bool {
+        self.size == MonsterSize::Boss
+    }
+}
+
+pub const MONSTERS: &[MonsterDef] = &[
+    // Small
+    MonsterDef { id: "bat", name: "Cave Bat", size: MonsterSize::Small, circle: 2, hp: 25, damage: 4, speed: 3.2, xp: 6, sprite: "monsters/bat/spritesheet.png", manifest: None },
+    MonsterDef { id: "rat", name: "Giant Rat", size: MonsterSize::Small, circle: 1, hp: 30, damage: 3, speed: 2.5, xp: 5, sprite: "monsters/rat/spritesheet.png", manifest: Some("sprites/manifests/rat.json") },
+    MonsterDef { id: "slime", name: "Slime", size: MonsterSize::Small, circle: 1, hp: 40, damage: 3, speed: 1.5, xp: 6, sprite: "monsters/slime/spritesheet.png", manifest: None },
+    // Medium
+    MonsterDef { id: "fox", name: "Wild Fox", size: MonsterSize::Medium, circle: 1, hp: 60, damage: 6, speed: 3.0, xp: 12, sprite: "monsters/fox/spritesheet.png", manifest: None },
+    MonsterDef { id: "spider", name: "Giant Spider", size: MonsterSize::Medium, circle: 2, hp: 70, damage: 10, speed: 2.8, xp: 18, sprite: "monsters/spider/spritesheet.png", manifest: None },
+    MonsterDef { id: "wolf", name: "Gray Wolf", size: MonsterSize::Medium, circle: 1, hp: 80, damage: 8, speed: 3.2, xp: 15, sprite: "monsters/wolf/spritesheet.png", manifest: None },
+    MonsterDef { id: "goblin", name: "Goblin", size: MonsterSize::Medium, circle: 2, hp: 90, damage: 9, speed: 2.4, xp: 18, sprite: "monsters/goblin/spritesheet.png", manifest: None },
+    MonsterDef { id: "skeleton", name: "Skeleton", size: MonsterSize::Medium, circle: 2, hp: 110, damage: 11, speed: 2.0, xp: 24, sprite: "monsters/skeleton/spritesheet.png", manifest: None },
+    MonsterDef { id: "zombie", name: "Zombie", size: MonsterSize::Medium, circle: 3, hp: 140, damage: 10, speed: 1.4, xp: 26, sprite: "monsters/zombie/spritesheet.png", manifest: None },
+    MonsterDef { id: "orc", name: "Orc Warrior", size: MonsterSize::Medium, circle: 4, hp: 160, damage: 14, speed: 2.2, xp: 32, sprite: "monsters/orc/spritesheet.png", manifest: None },
+    MonsterDef { id: "wolf_chief", name: "Wolf Chief", size: MonsterSize::Medium, circle: 1, hp: 260, damage: 14, speed: 3.4, xp: 70, sprite: "monsters/wolf/spritesheet.png", manifest: None },
+    MonsterDef { id: "scorpion_king", name: "Scorpion King", size: MonsterSize::Medium, circle: 2, hp: 480, damage: 20, speed: 2.6, xp: 150, sprite: "monsters/spider/spritesheet.png", manifest: None },
+    // Large
+    MonsterDef { id: "ghost", name: "Ghost", size: MonsterSize::Large, circle: 3, hp: 150, damage: 16, speed: 2.6, xp: 45, sprite: "monsters/ghost/spritesheet.png", manifest: None },
+    MonsterDef { id: "wraith", name: "Wraith", size: MonsterSize::Large, circle: 4, hp: 180, damage: 20, speed: 2.8, xp: 55, sprite: "monsters/wraith/spritesheet.png", manifest: None },
+    MonsterDef { id: "lich", name: "Lich", size: MonsterSize::Large, circle: 5, hp: 300, damage: 34, speed: 1.8, xp: 110, sprite: "monsters/lich/spritesheet.png", manifest: None },
+    MonsterDef { id: "dark_knight", name: "Dark Knight", size: MonsterSize::Large, circle: 5, hp: 320, damage: 26, speed: 2.0, xp: 80, sprite: "monsters/dark_knight/spritesheet.png", manifest: None },
+    MonsterDef { id: "troll", name: "Troll", size: MonsterSize::Large, circle: 3, hp: 380, damage: 24, speed: 1.6, xp: 85, sprite: "monsters/troll/spritesheet.png", manifest: None },
+    MonsterDef { id: "demon", name: "Demon", size: MonsterSize::Large, circle: 4, hp: 420, damage: 36, speed: 2.4, xp: 130, sprite: "monsters/demon/spritesheet.png", manifest: None },
+    MonsterDef { id: "golem", name: "Stone Golem", size: MonsterSize::Large, circle: 3, hp: 500, damage: 28, speed: 1.2, xp: 100, sprite: "monsters/golem/spritesheet.png", manifest: None },
+    MonsterDef { id: "ice_golem", name: "Ice Golem", size: MonsterSize::Large, circle: 3, hp: 900, damage: 32, speed: 1.1, xp: 260, sprite: "monsters/golem/spritesheet.png", manifest: None },
+    MonsterDef { id: "flame_lord", name: "Flame Lord", size: MonsterSize::Large, circle: 4, hp: 1200, damage: 44, speed: 2.2, xp: 420, sprite: "monsters/demon/spritesheet.png", manifest: None },
+    // Boss
+    MonsterDef { id: "arclich", name: "Arch Lich", size: MonsterSize::Boss, circle: 5, hp: 2000, damage: 50, speed: 1.6, xp: 800, sprite: "monsters/arclich/spritesheet.png", manifest: None },
+    MonsterDef { id: "demon_lord", name: "Demon Lord", size: MonsterSize::Boss, circle: 4, hp: 2600, damage: 60, speed: 1.8, xp: 1000, sprite: "monsters/demon_lord/spritesheet.png", manifest: None },
+    MonsterDef { id: "dragon", name: "Red Dragon", size: MonsterSize::Boss, circle: 5, hp: 3000, damage: 70, speed: 2.0, xp: 1200, sprite: "monsters/dragon/spritesheet.png", manifest: Some("sprites/manifests/dragon.json") },
+    MonsterDef { id: "dark_lord", name: "Dark Lord", size: MonsterSize::Boss, circle: 5, hp: 4500, damage: 85, speed: 1.9, xp: 2500, sprite: "monsters/demon_lord/spritesheet.png", manifest: None },
+];
+
+/// id로 몬스터 정의 조회
+pub fn monster_by_id(id: &str) -> Option<&'static MonsterDef> {
+    MONSTERS.iter().find(|monster| monster.id == id)
+}
+
+/// 서클에 나오는 몬스터
+pub fn monsters_in_circle(circle: u8) -> impl Iterator<Item = &'static MonsterDef> {
+    MONSTERS.iter().filter(move |monster| monster.circle == circle)
+}
@@ -0,0 +1,130 @@
+//! Skill Definitions - 스킬 정의
+//!
+//! 5대 클래스 × 11개 = 55개. 서클마다 1서클 3개, 2-5서클 2개씩 배웁니다.
+//! id는 `클래스 id × 100 + 순번` (`character_skills.skill_id`).
+//! 아이콘은 `public/assets/skills/`, 전용 아이콘이 없는 스킬은 비슷한 아이콘을 같이 씁니다.
+
+use crate::shared::domain::character::CharacterClass;
+
+/// 클래스당 스킬 수
+pub const SKILLS_PER_CLASS: usize = 11;
+
+/// 스킬 효과 분류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SkillKind {
+    /// 단일 대상 공격. `power` = 피해량
+    Attack,
+    /// 범위 공격. `power` = 피해량
+    Area,
+    /// 회복. `power` = 회복량
+    Heal,
+    /// 자신/아군 강화. `power` = 증가 수치 (%)
+    Buff,
+    /// 적 약화. `power` = 감소 수치 (%)
+    Debuff,
+    /// 순간 이동/돌진. `power` = 거리 (타일) 또는 피해량
+    Movement,
+}
+
+/// 스킬 정의
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SkillDef {
+    pub id: i32,
+    /// 영문 식별자 (`fireball`)
+    pub key: &'static str,
+    pub name: &'static str,
+    pub class: CharacterClass,
+    /// 배우는 서클 (1-5)
+    pub circle: u8,
+    pub required_level: i32,
+    pub kind: SkillKind,
+    pub mp_cost: i32,
+    /// 재사용 대기 시간 (초)
+    pub cooldown: f32,
+    /// 효과 수치, 의미는 [`SkillKind`] 참고
+    pub power: i32,
+    /// 64×64 아이콘 (assets 기준)
+    pub icon: &'static str,
+}
+
+pub const SKILLS: &[SkillDef] = &[
+    // Warrior - 전사
+    SkillDef { id: 101, key: "bash", name: "Bash", class: CharacterClass::Warrior, circle: 1, required_level: 1, kind: SkillKind::Attack, mp_cost: 5, cooldown: 3.0, power: 25, icon: "skills/bash.png" },
+    SkillDef { id: 102, key: "crash", name: "Crash", class: CharacterClass::Warrior, circle: 1, required_level: 5, kind: SkillKind::Attack, mp_cost: 10, cooldown: 6.0, power: 45, icon: "skills/crash.png" },
+    SkillDef { id: 103, key: "iron_will", name: "Iron Will", class: CharacterClass::Warrior, circle: 1, required_level: 12, kind: SkillKind::Buff, mp_cost: 15, cooldown: 30.0, power: 10, icon: "skills/iron_will.png" },
+    SkillDef { id: 104, key: "whirlwind", name: "Whirlwind", class: CharacterClass::Warrior, circle: 2, required_level: 21, kind: SkillKind::Area, mp_cost: 25, cooldown: 10.0, power: 80, icon: "skills/whirlwind.png" },
+    SkillDef { id: 105, key: "battle_cry", name: "Battle Cry", class: CharacterClass::Warrior, circle: 2, required_level: 30, kind: SkillKind::Buff, mp_cost: 30, cooldown: 45.0, power: 20, icon: "skills/battle_cry.png" },
+    SkillDef { id: 106, key: "ground_slam", name: "Ground Slam", class: CharacterClass::Warrior, circle: 3, required_level: 41, kind: SkillKind::Area, mp_cost: 45, cooldown: 14.0, power: 160, icon: "skills/ground_slam.png" },
+    SkillDef { id: 107, key: "shield_wall", name: "Shield Wall", class: CharacterClass::Warrior, circle: 3, required_level: 50, kind: SkillKind::Buff, mp_cost: 40, cooldown: 60.0, power: 35, icon: "skills/shield_wall.png" },
+    SkillDef { id: 108, key: "berserk", name: "Berserk", class: CharacterClass::Warrior, circle: 4, required_level: 61, kind: SkillKind::Buff, mp_cost: 60, cooldown: 90.0, power: 50, icon: "skills/berserk.png" },
+    SkillDef { id: 109, key: "earthquake", name: "Earthquake", class: CharacterClass::Warrior, circle: 4, required_level: 70, kind: SkillKind::Area, mp_cost: 80, cooldown: 20.0, power: 320, icon: "skills/earthquake.png" },
+    SkillDef { id: 110, key: "titan_strike", name: "Titan Strike", class: CharacterClass::Warrior, circle: 5, required_level: 81, kind: SkillKind::Attack, mp_cost: 100, cooldown: 25.0, power: 650, icon: "skills/titan_strike.png" },
+    SkillDef { id: 111, key: "immortal", name: "Immortal", class: CharacterClass::Warrior, circle: 5, required_level: 90, kind: SkillKind::Buff, mp_cost: 150, cooldown: 180.0, power: 100, icon: "skills/immortal.png" },
+    // Rogue - 도적
+    SkillDef { id: 201, key: "double_stab", name: "Double Stab", class: CharacterClass::Rogue, circle: 1, required_level: 1, kind: SkillKind::Attack, mp_cost: 6, cooldown: 3.0, power: 28, icon: "skills/double_stab.png" },
+    SkillDef { id: 202, key: "ambush", name: "Ambush", class: CharacterClass::Rogue, circle: 1, required_level: 5, kind: SkillKind::Attack, mp_cost: 12, cooldown: 8.0, power: 55, icon: "skills/ambush.png" },
+    SkillDef { id: 203, key: "evasion", name: "Evasion", class: CharacterClass::Rogue, circle: 1, required_level: 12, kind: SkillKind::Buff, mp_cost: 15, cooldown: 30.0, power: 15, icon: "skills/evasion.png" },
+    SkillDef { id: 204, key: "poison_blade", name: "Poison Blade", class: CharacterClass::Rogue, circle: 2, required_level: 21, kind: SkillKind::Attack, mp_cost: 25, cooldown: 10.0, power: 90, icon: "skills/poison_blade.png" },
+    SkillDef { id: 205, key: "shadow_step", name: "Shadow Step", class: CharacterClass::Rogue, circle: 2, required_level: 30, kind: SkillKind::Movement, mp_cost: 20, cooldown: 12.0, power: 6, icon: "skills/shadow_step.png" },
+    SkillDef { id: 206, key: "fan_of_knives", name: "Fan of Knives", class: CharacterClass::Rogue, circle: 3, required_level: 41, kind: SkillKind::Area, mp_cost: 45, cooldown: 12.0, power: 150, icon: "skills/double_stab.png" },
+    SkillDef { id: 207, key: "vanish", name: "Vanish", class: CharacterClass::Rogue, circle: 3, required_level: 50, kind: SkillKind::Buff, mp_cost: 40, cooldown: 60.0, power: 30, icon: "skills/vanish.png" },
+    SkillDef { id: 208, key: "deadly_poison", name: "Deadly Poison", class: CharacterClass::Rogue, circle: 4, required_level: 61, kind: SkillKind::Attack, mp_cost: 60, cooldown: 16.0, power: 300, icon: "skills/deadly_poison.png" },
+    SkillDef { id: 209, key: "shadow_dance", name: "Shadow Dance", class: CharacterClass::Rogue, circle: 4, required_level: 70, kind: SkillKind::Buff, mp_cost: 70, cooldown: 90.0, power: 50, icon: "skills/shadow_dance.png" },
+    SkillDef { id: 210, key: "assassinate", name: "Assassinate", class: CharacterClass::Rogue, circle: 5, required_level: 81, kind: SkillKind::Attack, mp_cost: 100, cooldown: 30.0, power: 700, icon: "skills/assassinate.png" },
+    SkillDef { id: 211, key: "death_mark", name: "Death Mark", class: CharacterClass::Rogue, circle: 5, required_level: 90, kind: SkillKind::Debuff, mp_cost: 120, cooldown: 60.0, power: 100, icon: "skills/death_mark.png" },
+    // Mage - 마법사
+    SkillDef { id: 301, key: "fireball", name: "Fireball", class: CharacterClass::Mage, circle: 1, required_level: 1, kind: SkillKind::Attack, mp_cost: 8, cooldown: 2.0, power: 30, icon: "skills/fireball.png" },
+    SkillDef { id: 302, key: "thunder_bolt", name: "Thunder Bolt", class: CharacterClass::Mage, circle: 1, required_level: 5, kind: SkillKind::Attack, mp_cost: 14, cooldown: 5.0, power: 55, icon: "skills/thunder_bolt.png" },
+    SkillDef { id: 303, key: "ice_shield", name: "Ice Shield", class: CharacterClass::Mage, circle: 1, required_level: 12, kind: SkillKind::Buff, mp_cost: 20, cooldown: 30.0, power: 20, icon: "skills/ice_shield.png" },
+    SkillDef { id: 304, key: "flame_wave", name: "Flame Wave", class: CharacterClass::Mage, circle: 2, required_level: 21, kind: SkillKind::Area, mp_cost: 30, cooldown: 8.0, power: 95, icon: "skills/flame_wave.png" },
+    SkillDef { id: 305, key: "teleport", name: "Teleport", class: CharacterClass::Mage, circle: 2, required_level: 30, kind: SkillKind::Movement, mp_cost: 25, cooldown: 15.0, power: 8, icon: "skills/teleport.png" },
+    SkillDef { id: 306, key: "blizzard", name: "Blizzard", class: CharacterClass::Mage, circle: 3, required_level: 41, kind: SkillKind::Area, mp_cost: 55, cooldown: 14.0, power: 180, icon: "skills/blizzard.png" },
+    SkillDef { id: 307, key: "mana_shield", name: "Mana Shield", class: CharacterClass::Mage, circle: 3, required_level: 50, kind: SkillKind::Buff, mp_cost: 50, cooldown: 60.0, power: 40, icon: "skills/mana_shield.png" },
+    SkillDef { id: 308, key: "meteor", name: "Meteor", class: CharacterClass::Mage, circle: 4, required_level: 61, kind: SkillKind::Area, mp_cost: 90, cooldown: 20.0, power: 380, icon: "skills/meteor.png" },
+    SkillDef { id: 309, key: "time_stop", name: "Time Stop", class: CharacterClass::Mage, circle: 4, required_level: 70, kind: SkillKind::Debuff, mp_cost: 100, cooldown: 120.0, power: 4, icon: "skills/time_stop.png" },
+    SkillDef { id: 310, key: "armageddon", name: "Armageddon", class: CharacterClass::Mage, circle: 5, required_level: 81, kind: SkillKind::Area, mp_cost: 160, cooldown: 45.0, power: 900, icon: "skills/arcane_explosion.png" },
+    SkillDef { id: 311, key: "arcane_mastery", name: "Arcane Mastery", class: CharacterClass::Mage, circle: 5, required_level: 90, kind: SkillKind::Buff, mp_cost: 120, cooldown: 180.0, power: 100, icon: "skills/infinity.png" },
+    // Cleric - 성직자
+    SkillDef { id: 401, key: "heal", name: "Heal", class: CharacterClass::Cleric, circle: 1, required_level: 1, kind: SkillKind::Heal, mp_cost: 8, cooldown: 3.0, power: 40, icon: "skills/heal.png" },
+    SkillDef { id: 402, key: "holy_bolt", name: "Holy Bolt", class: CharacterClass::Cleric, circle: 1, required_level: 5, kind: SkillKind::Attack, mp_cost: 10, cooldown: 4.0, power: 40, icon: "skills/holy_bolt.png" },
+    SkillDef { id: 403, key: "great_heal", name: "Great Heal", class: CharacterClass::Cleric, circle: 1, required_level: 12, kind: SkillKind::Heal, mp_cost: 20, cooldown: 8.0, power: 110, icon: "skills/great_heal.png" },
+    SkillDef { id: 404, key: "blessing", name: "Blessing", class: CharacterClass::Cleric, circle: 2, required_level: 21, kind: SkillKind::Buff, mp_cost: 25, cooldown: 45.0, power: 15, icon: "skills/bless.png" },
+    SkillDef { id: 405, key: "sanctuary", name: "Sanctuary", class: CharacterClass::Cleric, circle: 2, required_level: 30, kind: SkillKind::Heal, mp_cost: 35, cooldown: 30.0, power: 60, icon: "skills/divine_shield.png" },
+    SkillDef { id: 406, key: "mass_heal", name: "Mass Heal", class: CharacterClass::Cleric, circle: 3, required_level: 41, kind: SkillKind::Heal, mp_cost: 50, cooldown: 20.0, power: 220, icon: "skills/regeneration.png" },
+    SkillDef { id: 407, key: "holy_armor", name: "Holy Armor", class: CharacterClass::Cleric, circle: 3, required_level: 50, kind: SkillKind::Buff, mp_cost: 45, cooldown: 60.0, power: 35, icon: "skills/divine_shield.png" },
+    SkillDef { id: 408, key: "resurrection", name: "Resurrection", class: CharacterClass::Cleric, circle: 4, required_level: 61, kind: SkillKind::Heal, mp_cost: 100, cooldown: 300.0, power: 50, icon: "skills/resurrection.png" },
+    SkillDef { id: 409, key: "divine_judgment", name: "Divine Judgment", class: CharacterClass::Cleric, circle: 4, required_level: 70, kind: SkillKind::Area, mp_cost: 85, cooldown: 20.0, power: 340, icon: "skills/divine_judgement.png" },
+    SkillDef { id: 410, key: "guardian_angel", name: "Guardian Angel", class: CharacterClass::Cleric, circle: 5, required_level: 81, kind: SkillKind::Buff, mp_cost: 130, cooldown: 180.0, power: 60, icon: "skills/angel.png" },
+    SkillDef { id: 411, key: "divine_intervention", name: "Divine Intervention", class: CharacterClass::Cleric, circle: 5, required_level: 90, kind: SkillKind::Area, mp_cost: 160, cooldown: 60.0, power: 800, icon: "skills/smite.png" },
+    // Martial Artist - 무도가
+    SkillDef { id: 501, key: "punch", name: "Punch", class: CharacterClass::MartialArtist, circle: 1, required_level: 1, kind: SkillKind::Attack, mp_cost: 4, cooldown: 2.0, power: 24, icon: "skills/punch.png" },
+    SkillDef { id: 502, key: "power_kick", name: "Power Kick", class: CharacterClass::MartialArtist, circle: 1, required_level: 5, kind: SkillKind::Attack, mp_cost: 10, cooldown: 6.0, power: 50, icon: "skills/power_kick.png" },
+    SkillDef { id: 503, key: "inner_peace", name: "Inner Peace", class: CharacterClass::MartialArtist, circle: 1, required_level: 12, kind: SkillKind::Heal, mp_cost: 15, cooldown: 30.0, power: 60, icon: "skills/inner_peace.png" },
+    SkillDef { id: 504, key: "dragon_fist", name: "Dragon Fist", class: CharacterClass::MartialArtist, circle: 2, required_level: 21, kind: SkillKind::Attack, mp_cost: 25, cooldown: 9.0, power: 100, icon: "skills/dragon_fist.png" },
+    SkillDef { id: 505, key: "iron_body", name: "Iron Body", class: CharacterClass::MartialArtist, circle: 2, required_level: 30, kind: SkillKind::Buff, mp_cost: 30, cooldown: 60.0, power: 25, icon: "skills/iron_body.png" },
+    SkillDef { id: 506, key: "tiger_palm", name: "Tiger Palm", class: CharacterClass::MartialArtist, circle: 3, required_level: 41, kind: SkillKind::Attack, mp_cost: 40, cooldown: 10.0, power: 190, icon: "skills/combo_strike.png" },
+    SkillDef { id: 507, key: "flying_kick", name: "Flying Kick", class: CharacterClass::MartialArtist, circle: 3, required_level: 50, kind: SkillKind::Movement, mp_cost: 45, cooldown: 12.0, power: 210, icon: "skills/flying_kick.png" },
+    SkillDef { id: 508, key: "pressure_point", name: "Pressure Point", class: CharacterClass::MartialArtist, circle: 4, required_level: 61, kind: SkillKind::Debuff, mp_cost: 55, cooldown: 30.0, power: 40, icon: "skills/focus.png" },
+    SkillDef { id: 509, key: "chi_burst", name: "Chi Burst", class: CharacterClass::MartialArtist, circle: 4, required_level: 70, kind: SkillKind::Area, mp_cost: 75, cooldown: 18.0, power: 360, icon: "skills/ki_blast.png" },
+    SkillDef { id: 510, key: "hundred_fists", name: "Hundred Fists", class: CharacterClass::MartialArtist, circle: 5, required_level: 81, kind: SkillKind::Attack, mp_cost: 110, cooldown: 30.0, power: 720, icon: "skills/ultimate_punch.png" },
+    SkillDef { id: 511, key: "enlightenment", name: "Enlightenment", class: CharacterClass::MartialArtist, circle: 5, required_level: 90, kind: SkillKind::Buff, mp_cost: 130, cooldown: 180.0, power: 100, icon: "skills/inner_peace.png" },
+];
+
+/// id로 스킬 정의 조회
+pub fn skill_by_id(id: i32) -> Option<&'static SkillDef> {
+    SKILLS.iter().find(|skill| skill.id == id)
+}
+
+pub fn skill_by_key(key: &str) -> Option<&'static SkillDef> {
+    SKILLS.iter().find(|skill| skill.key == key)
+}
+
+/// 클래스의 스킬, 배우는 순서대로
+pub fn class_skills(class: CharacterClass) -> impl Iterator<Item = &'static SkillDef> {
+    SKILLS.iter().filter(move |skill| skill.class == class)
+}
+
+/// 이 레벨에서 배울 수 있는 클래스 스킬
+pub fn learnable_skills(class: CharacterClass, level: i32) -> impl Iterator<Item = &'static SkillDef> {
+    class_skills(class).filter(move |skill| skill.required_level <= level)
+}
@@ -0,0 +1,89 @@
+//! Auth - 계정 등록/로그인 요청과 응답
+//!
+//! `POST /api/auth/register`, `POST /api/auth/login`
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// 아이디 길이 (문자)
+pub const USERNAME_MIN_LEN: usize = 3;
+pub const USERNAME_MAX_LEN: usize = 20;
+
+/// 비밀번호 길이 (문자)
+pub const PASSWORD_MIN_LEN: usize = 8;
+pub const PASSWORD_MAX_LEN: usize = 128;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterRequest {
+    pub username: String,
+    pub password: String,
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginRequest {
+    pub username: String,
+    pub password: String,
+}
+
+/// 계정 정보 (비밀번호 해시 제외)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserResponse {
+    pub id: Uuid,
+    pub username: String,
+    pub email: Option<String>,
+}
+
+/// 로그인 성공. 이후 요청은 `Authorization: Bearer <token>` 헤더를 붙입니다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoginResponse {
+    pub token: String,
+    /// 토큰 만료 시각 (유닉스 초)
+    pub expires_at: i64,
+    pub user: UserResponse,
+}
+
+/// 입력값 검증 오류
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AuthValidationError {
+    #[error("Username must be {USERNAME_MIN_LEN}-{USERNAME_MAX_LEN} characters")]
+    UsernameLength,
+    #[error("Username may only contain letters, digits and underscores")]
+    UsernameCharacters,
+    #[error("Password must be {PASSWORD_MIN_LEN}-{PASSWORD_MAX_LEN} characters")]
+    PasswordLength,
+    #[error("Email address is not valid")]
+    InvalidEmail,
+}
+
+impl RegisterRequest {
+    pub fn validate(&self) -> Result<(), AuthValidationError> {
+        let length = self.username.chars().count();
+        if !(USERNAME_MIN_LEN..=USERNAME_MAX_LEN).contains(&length) {
+            return Err(AuthValidationError::UsernameLength);
+        }
+        if !self.username.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(AuthValidationError::UsernameCharacters);
+        }
+        if !(PASSWORD_MIN_LEN..=PASSWORD_MAX_LEN).contains(&self.password.chars().count()) {
+            return Err(AuthValidationError::PasswordLength);
+        }
+        if let Some(email) = &self.email
+            && !is_plausible_email(email)
+        {
+            return Err(AuthValidationError::InvalidEmail);
+        }
+        Ok(())
+    }
+}
+
+/// `a@b.c` 꼴인지만 확인 (실제 확인은 메일 발송으로)
+fn is_plausible_email(email: &str) -> bool {
+    email.len() <= 255
+        && !email.contains(char::is_whitespace)
+        && email
+            .split_once('@')
+            .is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.') && !domain.starts_with('.') && !domain.ends_with('.'))
+}
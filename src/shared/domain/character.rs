@@ -0,0 +1,264 @@
+//! Character - 캐릭터 모델과 API 타입
+//!
+//! `characters` 테이블 한 행 = [`Character`].
+//! `GET/POST /api/characters`, `GET/DELETE /api/characters/{id}`
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+use crate::shared::domain::DomainError;
+
+/// 캐릭터 이름 길이 (문자)
+pub const CHARACTER_NAME_MIN_LEN: usize = 2;
+pub const CHARACTER_NAME_MAX_LEN: usize = 12;
+
+/// 만렙 (`characters.level` CHECK)
+pub const MAX_LEVEL: i32 = 99;
+
+/// 새 캐릭터의 값 (`characters` 컬럼 기본값)
+pub const NEW_CHARACTER_HP: i32 = 100;
+pub const NEW_CHARACTER_MP: i32 = 50;
+pub const NEW_CHARACTER_GOLD: i64 = 100;
+pub const NEW_CHARACTER_MAP: &str = "village_milles";
+pub const NEW_CHARACTER_POSITION: (f64, f64) = (400.0, 300.0);
+
+/// 5대 클래스. 값은 `classes` 테이블의 id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CharacterClass {
+    Warrior = 1,
+    Rogue = 2,
+    Mage = 3,
+    Cleric = 4,
+    MartialArtist = 5,
+}
+
+impl CharacterClass {
+    pub const ALL: [CharacterClass; 5] = [
+        CharacterClass::Warrior,
+        CharacterClass::Rogue,
+        CharacterClass::Mage,
+        CharacterClass::Cleric,
+        CharacterClass::MartialArtist,
+    ];
+
+    /// `classes.id`
+    pub const fn id(self) -> i32 {
+        self as i32
+    }
+
+    pub fn from_id(id: i32) -> Option<Self> {
+        Self::ALL.into_iter().find(|class| class.id() == id)
+    }
+
+    pub fn try_from_id(id: i32) -> Result<Self, DomainError> {
+        Self::from_id(id).ok_or_else(|| DomainError::Unknown {
+            what: "class id",
+            value: id.to_string(),
+        })
+    }
+
+    /// `classes.name`
+    pub const fn name(self) -> &'static str {
+        match self {
+            CharacterClass::Warrior => "Warrior",
+            CharacterClass::Rogue => "Rogue",
+            CharacterClass::Mage => "Mage",
+            CharacterClass::Cleric => "Cleric",
+            CharacterClass::MartialArtist => "MartialArtist",
+        }
+    }
+}
+
+/// `characters.gender` (`'male'`, `'female'`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Gender {
+    Male,
+    Female,
+}
+
+impl Gender {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Gender::Male => "male",
+            Gender::Female => "female",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "male" => Some(Gender::Male),
+            "female" => Some(Gender::Female),
+            _ => None,
+        }
+    }
+
+    pub fn try_parse(value: &str) -> Result<Self, DomainError> {
+        Self::parse(value).ok_or_else(|| DomainError::Unknown {
+            what: "gender",
+            value: value.to_string(),
+        })
+    }
+}
+
+/// 다섯 가지 능력치 (힘, 민첩, 지능, 지혜, 체력)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Stats {
+    pub str: i32,
+    pub dex: i32,
+    pub int: i32,
+    pub wis: i32,
+    pub con: i32,
+}
+
+impl Stats {
+    pub const ZERO: Stats = Stats::new(0, 0, 0, 0, 0);
+
+    pub const fn new(str: i32, dex: i32, int: i32, wis: i32, con: i32) -> Self {
+        Self { str, dex, int, wis, con }
+    }
+
+    /// 분배한 능력치처럼 음수가 될 수 없는 값
+    pub fn non_negative(str: i32, dex: i32, int: i32, wis: i32, con: i32) -> Result<Self, DomainError> {
+        let stats = Self::new(str, dex, int, wis, con);
+        if stats.as_array().iter().any(|value| *value < 0) {
+            return Err(DomainError::NegativeStats);
+        }
+        Ok(stats)
+    }
+
+    /// `[str, dex, int, wis, con]`
+    pub const fn as_array(self) -> [i32; 5] {
+        [self.str, self.dex, self.int, self.wis, self.con]
+    }
+
+    /// 능력치 합계. i32를 넘으면 오류.
+    pub fn total(self) -> Result<i32, DomainError> {
+        self.as_array()
+            .into_iter()
+            .try_fold(0i32, i32::checked_add)
+            .ok_or(DomainError::StatOverflow)
+    }
+
+    /// 항목별 합. 하나라도 i32를 넘으면 오류.
+    pub fn checked_add(self, other: Stats) -> Result<Stats, DomainError> {
+        let add = |a: i32, b: i32| a.checked_add(b).ok_or(DomainError::StatOverflow);
+        Ok(Stats::new(
+            add(self.str, other.str)?,
+            add(self.dex, other.dex)?,
+            add(self.int, other.int)?,
+            add(self.wis, other.wis)?,
+            add(self.con, other.con)?,
+        ))
+    }
+}
+
+/// 캐릭터 한 명 (`characters` 한 행)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Character {
+    pub id: Uuid,
+    pub name: String,
+    pub class: CharacterClass,
+    pub gender: Gender,
+    pub level: i32,
+    /// 현재 레벨에서 얻은 경험치
+    pub exp: i64,
+    pub total_exp: i64,
+    pub hp: i32,
+    pub mp: i32,
+    /// 분배한 추가 능력치 (기본 능력치는 클래스 const)
+    pub bonus_stats: Stats,
+    /// 아직 분배하지 않은 능력치 포인트
+    pub stat_points: i32,
+    pub gold: i64,
+    /// `MapFile.id`
+    pub current_map: String,
+    pub pos_x: f64,
+    pub pos_y: f64,
+    pub monsters_killed: i32,
+    pub deaths: i32,
+    pub play_time_seconds: i64,
+    pub created_at: DateTime<Utc>,
+    pub last_played_at: DateTime<Utc>,
+}
+
+impl Character {
+    /// 레벨 1, 컬럼 기본값으로 채운 새 캐릭터
+    pub fn new(name: &str, class: CharacterClass, gender: Gender, now: DateTime<Utc>) -> Result<Self, DomainError> {
+        validate_character_name(name)?;
+        Ok(Self {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            class,
+            gender,
+            level: 1,
+            exp: 0,
+            total_exp: 0,
+            hp: NEW_CHARACTER_HP,
+            mp: NEW_CHARACTER_MP,
+            bonus_stats: Stats::ZERO,
+            stat_points: 0,
+            gold: NEW_CHARACTER_GOLD,
+            current_map: NEW_CHARACTER_MAP.to_string(),
+            pos_x: NEW_CHARACTER_POSITION.0,
+            pos_y: NEW_CHARACTER_POSITION.1,
+            monsters_killed: 0,
+            deaths: 0,
+            play_time_seconds: 0,
+            created_at: now,
+            last_played_at: now,
+        })
+    }
+
+    pub fn set_level(&mut self, level: i32) -> Result<(), DomainError> {
+        if !(1..=MAX_LEVEL).contains(&level) {
+            return Err(DomainError::Level(level));
+        }
+        self.level = level;
+        Ok(())
+    }
+
+    /// 미분배 포인트로 추가 능력치 올리기
+    pub fn spend_stat_points(&mut self, points: Stats) -> Result<(), DomainError> {
+        let points = Stats::non_negative(points.str, points.dex, points.int, points.wis, points.con)?;
+        let needed = points.total()?;
+        if needed > self.stat_points {
+            return Err(DomainError::NotEnoughStatPoints {
+                needed,
+                available: self.stat_points,
+            });
+        }
+        self.bonus_stats = self.bonus_stats.checked_add(points)?;
+        self.stat_points -= needed;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCharacterRequest {
+    pub name: String,
+    pub class: CharacterClass,
+    pub gender: Gender,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CharacterNameError {
+    #[error("Character name must be {CHARACTER_NAME_MIN_LEN}-{CHARACTER_NAME_MAX_LEN} characters")]
+    Length,
+    #[error("Character name may only contain letters and digits")]
+    Characters,
+}
+
+/// 한글 등 모든 문자와 숫자 허용, 공백/기호 불가
+pub fn validate_character_name(name: &str) -> Result<(), CharacterNameError> {
+    let length = name.chars().count();
+    if !(CHARACTER_NAME_MIN_LEN..=CHARACTER_NAME_MAX_LEN).contains(&length) {
+        return Err(CharacterNameError::Length);
+    }
+    if !name.chars().all(char::is_alphanumeric) {
+        return Err(CharacterNameError::Characters);
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+//! Dungeon - 던전 진행도
+//!
+//! `character_dungeon_progress` 한 행 = [`DungeonProgress`].
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DungeonProgress {
+    pub character_id: Uuid,
+    /// `shared::data::maps` 던전 id
+    pub dungeon_id: i32,
+    pub clear_count: i32,
+    /// 가장 빠른 클리어 (밀리초)
+    pub best_clear_time_ms: Option<i64>,
+    pub last_entered_at: Option<DateTime<Utc>>,
+}
+
+impl DungeonProgress {
+    /// 아직 들어간 적 없는 던전
+    pub fn new(character_id: Uuid, dungeon_id: i32) -> Self {
+        Self {
+            character_id,
+            dungeon_id,
+            clear_count: 0,
+            best_clear_time_ms: None,
+            last_entered_at: None,
+        }
+    }
+
+    pub fn enter(&mut self, now: DateTime<Utc>) {
+        self.last_entered_at = Some(now);
+    }
+
+    /// 클리어 횟수를 올리고, 더 빠르면 최고 기록 갱신. 새 기록이면 true.
+    pub fn record_clear(&mut self, time: Duration) -> bool {
+        self.clear_count += 1;
+        let time_ms = i64::try_from(time.as_millis()).unwrap_or(i64::MAX);
+        let is_best = self.best_clear_time_ms.is_none_or(|best| time_ms < best);
+        if is_best {
+            self.best_clear_time_ms = Some(time_ms);
+        }
+        is_best
+    }
+}
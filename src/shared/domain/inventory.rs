@@ -0,0 +1,192 @@
+//! Inventory - 인벤토리 아이템과 장비 슬롯
+//!
+//! `character_inventory` 한 행 = [`InventoryItem`].
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::shared::domain::DomainError;
+
+/// 장착 부위 (`character_inventory.equipped_slot`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EquipSlot {
+    Weapon,
+    Shield,
+    Helmet,
+    Armor,
+    Pants,
+    Cape,
+    Gloves,
+    Boots,
+    Belt,
+    Ring,
+    Amulet,
+}
+
+impl EquipSlot {
+    pub const ALL: [EquipSlot; 11] = [
+        EquipSlot::Weapon,
+        EquipSlot::Shield,
+        EquipSlot::Helmet,
+        EquipSlot::Armor,
+        EquipSlot::Pants,
+        EquipSlot::Cape,
+        EquipSlot::Gloves,
+        EquipSlot::Boots,
+        EquipSlot::Belt,
+        EquipSlot::Ring,
+        EquipSlot::Amulet,
+    ];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            EquipSlot::Weapon => "weapon",
+            EquipSlot::Shield => "shield",
+            EquipSlot::Helmet => "helmet",
+            EquipSlot::Armor => "armor",
+            EquipSlot::Pants => "pants",
+            EquipSlot::Cape => "cape",
+            EquipSlot::Gloves => "gloves",
+            EquipSlot::Boots => "boots",
+            EquipSlot::Belt => "belt",
+            EquipSlot::Ring => "ring",
+            EquipSlot::Amulet => "amulet",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        Self::ALL.into_iter().find(|slot| slot.as_str() == value).ok_or_else(|| DomainError::Unknown {
+            what: "equip slot",
+            value: value.to_string(),
+        })
+    }
+
+    /// 이 부위 장비가 그려지는 Paper Doll 레이어. 외형에 안 보이는 부위는 None.
+    pub const fn paper_doll_layer(self) -> Option<PaperDollLayer> {
+        match self {
+            EquipSlot::Pants => Some(PaperDollLayer::Pants),
+            EquipSlot::Armor => Some(PaperDollLayer::Armor),
+            EquipSlot::Helmet => Some(PaperDollLayer::Helmet),
+            EquipSlot::Weapon => Some(PaperDollLayer::Weapon),
+            EquipSlot::Shield => Some(PaperDollLayer::Shield),
+            EquipSlot::Cape => Some(PaperDollLayer::Cape),
+            EquipSlot::Gloves | EquipSlot::Boots | EquipSlot::Belt | EquipSlot::Ring | EquipSlot::Amulet => None,
+        }
+    }
+}
+
+/// Paper Doll 레이어 순서 (ASSETS.md): 낮은 값이 뒤에 그려집니다.
+/// 클라이언트 렌더링과 장비 슬롯이 같은 순서를 쓰도록 여기에만 정의합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PaperDollLayer {
+    Shadow,
+    Body,
+    Pants,
+    Armor,
+    Hair,
+    Helmet,
+    Weapon,
+    Shield,
+    Cape,
+    Effect,
+}
+
+impl PaperDollLayer {
+    /// 그리는 순서 (0 = 맨 뒤)
+    pub const fn z_index(self) -> usize {
+        self as usize
+    }
+
+    /// 이 레이어 시트가 있는 폴더. 그림자와 몸은 장착 불가.
+    pub const fn asset_dir(self) -> Option<&'static str> {
+        match self {
+            PaperDollLayer::Pants => Some("equipment/pants"),
+            PaperDollLayer::Armor => Some("equipment/armor"),
+            PaperDollLayer::Hair => Some("characters/hair"),
+            PaperDollLayer::Helmet => Some("equipment/helmets"),
+            PaperDollLayer::Weapon => Some("equipment/weapons"),
+            PaperDollLayer::Shield => Some("equipment/shields"),
+            PaperDollLayer::Cape => Some("equipment/capes"),
+            PaperDollLayer::Effect => Some("effects"),
+            PaperDollLayer::Shadow | PaperDollLayer::Body => None,
+        }
+    }
+
+    /// 레이어가 따라가는 앵커 (`sprites/anchors/*.json`의 키)
+    pub const fn anchor(self) -> Option<&'static str> {
+        match self {
+            PaperDollLayer::Shadow => Some("feet"),
+            PaperDollLayer::Body => None,
+            PaperDollLayer::Pants | PaperDollLayer::Armor => Some("body"),
+            PaperDollLayer::Hair | PaperDollLayer::Helmet => Some("head"),
+            PaperDollLayer::Weapon => Some("right_hand"),
+            PaperDollLayer::Shield => Some("left_hand"),
+            PaperDollLayer::Cape => Some("back"),
+            PaperDollLayer::Effect => Some("effect_center"),
+        }
+    }
+}
+
+/// 캐릭터가 가진 아이템 한 묶음
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InventoryItem {
+    pub id: Uuid,
+    pub character_id: Uuid,
+    /// `shared::data::items` id
+    pub item_id: i32,
+    /// 1 이상
+    pub quantity: i32,
+    /// None이면 가방, 숫자면 슬롯 위치
+    pub slot_index: Option<i32>,
+    /// 장착 중이면 부위 (`is_equipped` + `equipped_slot`)
+    pub equipped_slot: Option<EquipSlot>,
+    /// 0 이상
+    pub enhancement_level: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl InventoryItem {
+    pub fn new(character_id: Uuid, item_id: i32, quantity: i32, now: DateTime<Utc>) -> Result<Self, DomainError> {
+        let mut item = Self {
+            id: Uuid::new_v4(),
+            character_id,
+            item_id,
+            quantity: 1,
+            slot_index: None,
+            equipped_slot: None,
+            enhancement_level: 0,
+            created_at: now,
+        };
+        item.set_quantity(quantity)?;
+        Ok(item)
+    }
+
+    pub fn is_equipped(&self) -> bool {
+        self.equipped_slot.is_some()
+    }
+
+    pub fn set_quantity(&mut self, quantity: i32) -> Result<(), DomainError> {
+        if quantity < 1 {
+            return Err(DomainError::Quantity(quantity));
+        }
+        self.quantity = quantity;
+        Ok(())
+    }
+
+    pub fn set_enhancement_level(&mut self, level: i32) -> Result<(), DomainError> {
+        if level < 0 {
+            return Err(DomainError::Enhancement(level));
+        }
+        self.enhancement_level = level;
+        Ok(())
+    }
+
+    pub fn equip(&mut self, slot: EquipSlot) {
+        self.equipped_slot = Some(slot);
+    }
+
+    pub fn unequip(&mut self) {
+        self.equipped_slot = None;
+    }
+}
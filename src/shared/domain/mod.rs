@@ -1 +1,42 @@
-// Empty domain module for now
+//! Domain - 클라이언트와 서버가 함께 쓰는 도메인 모델과 API 타입
+//!
+//! `migrations/`의 테이블을 그대로 옮긴 타입들입니다.
+//! 생성자와 변경 메서드는 SQL CHECK 제약과 같은 규칙으로 값을 검증합니다.
+
+pub mod auth;
+pub mod character;
+pub mod dungeon;
+pub mod inventory;
+pub mod skill;
+
+use thiserror::Error;
+
+pub use character::{Character, CharacterClass, Gender, Stats};
+pub use dungeon::DungeonProgress;
+pub use inventory::{EquipSlot, InventoryItem, PaperDollLayer};
+pub use skill::{CharacterSkill, SkillSlot};
+
+/// 도메인 값 검증 오류
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DomainError {
+    #[error(transparent)]
+    CharacterName(#[from] character::CharacterNameError),
+    #[error("Level {0} is outside 1-{max}", max = character::MAX_LEVEL)]
+    Level(i32),
+    #[error("Stats cannot be negative")]
+    NegativeStats,
+    #[error("Needs {needed} stat points but only {available} are unspent")]
+    NotEnoughStatPoints { needed: i32, available: i32 },
+    #[error("Stats are too large")]
+    StatOverflow,
+    #[error("Item quantity must be at least 1, got {0}")]
+    Quantity(i32),
+    #[error("Enhancement level cannot be negative, got {0}")]
+    Enhancement(i32),
+    #[error("Skill level must be at least 1, got {0}")]
+    SkillLevel(i32),
+    #[error("Skill slot must be 1-{max}, got {0}", max = skill::SKILL_SLOT_COUNT)]
+    SkillSlot(i32),
+    #[error("Unknown {what} '{value}'")]
+    Unknown { what: &'static str, value: String },
+}
@@ -0,0 +1,83 @@
+//! Skill - 습득한 스킬과 스킬바 슬롯
+//!
+//! `character_skills` 한 행 = [`CharacterSkill`].
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::shared::domain::DomainError;
+
+/// 스킬바 칸 수 (키 1-5)
+pub const SKILL_SLOT_COUNT: u8 = 5;
+
+/// 스킬바 칸 번호 (1부터)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "i32", into = "i32")]
+pub struct SkillSlot(u8);
+
+impl SkillSlot {
+    pub fn new(slot: i32) -> Result<Self, DomainError> {
+        if (1..=SKILL_SLOT_COUNT as i32).contains(&slot) {
+            Ok(Self(slot as u8))
+        } else {
+            Err(DomainError::SkillSlot(slot))
+        }
+    }
+
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+
+    /// 0부터 세는 순서 (UI 배열 인덱스)
+    pub const fn index(self) -> usize {
+        self.0 as usize - 1
+    }
+}
+
+impl TryFrom<i32> for SkillSlot {
+    type Error = DomainError;
+
+    fn try_from(slot: i32) -> Result<Self, Self::Error> {
+        Self::new(slot)
+    }
+}
+
+impl From<SkillSlot> for i32 {
+    fn from(slot: SkillSlot) -> Self {
+        slot.0 as i32
+    }
+}
+
+/// 캐릭터가 배운 스킬
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CharacterSkill {
+    pub character_id: Uuid,
+    /// `shared::data::skills` id
+    pub skill_id: i32,
+    /// 1 이상
+    pub skill_level: i32,
+    /// 스킬바에 올린 칸
+    pub slot: Option<SkillSlot>,
+    pub learned_at: DateTime<Utc>,
+}
+
+impl CharacterSkill {
+    /// 레벨 1로 배운 스킬
+    pub fn learn(character_id: Uuid, skill_id: i32, now: DateTime<Utc>) -> Self {
+        Self {
+            character_id,
+            skill_id,
+            skill_level: 1,
+            slot: None,
+            learned_at: now,
+        }
+    }
+
+    pub fn set_level(&mut self, level: i32) -> Result<(), DomainError> {
+        if level < 1 {
+            return Err(DomainError::SkillLevel(level));
+        }
+        self.skill_level = level;
+        Ok(())
+    }
+}
@@ -0,0 +1,344 @@
+//! Map File - 맵 파일 포맷
+//!
+//! `public/assets/maps/<id>.json` 으로 직접 작성하는 맵.
+//! 타일 레이어, 장식, 자원, NPC, 몬스터 출현 구역, 포탈을 담습니다.
+//! 위치는 모두 타일 좌표 `[x, y]` 입니다 (타일 (0, 0) = -X/-Z 모서리).
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use thiserror::Error;
+use crate::shared::constants::{MAP_HEIGHT_DEFAULT, MAP_WIDTH_DEFAULT};
+use crate::shared::data::maps::{MapKind, map_by_id};
+use crate::shared::data::monsters::monster_by_id;
+use crate::shared::tilemap::{TileMap, TileType};
+
+/// 랜덤 패치가 덮지 않는 플레이어 시작 위치 주변 (타일)
+pub const SPAWN_CLEAR_RADIUS: i32 = 4;
+
+/// 채집 가능한 자원 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceType {
+    Wood,
+    Gold,
+}
+
+impl ResourceType {
+    pub const fn sprite(self) -> &'static str {
+        match self {
+            ResourceType::Wood => "decorations/tree.png",
+            ResourceType::Gold => "decorations/rock.png",
+        }
+    }
+
+    /// 스프라이트 한 변의 크기 (월드 유닛)
+    pub const fn sprite_size(self) -> f32 {
+        match self {
+            ResourceType::Wood => 3.0,
+            ResourceType::Gold => 1.5,
+        }
+    }
+}
+
+/// 맵 한 장
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapFile {
+    /// 파일 이름과 같은 id (`village_milles`)
+    pub id: String,
+    pub name: String,
+    /// 타일 단위 크기, 생략하면 `MAP_WIDTH_DEFAULT` x `MAP_HEIGHT_DEFAULT`
+    #[serde(default = "default_map_width")]
+    pub width: u32,
+    #[serde(default = "default_map_height")]
+    pub height: u32,
+    /// 레이어가 덮지 않은 칸의 타일
+    #[serde(default)]
+    pub base_tile: TileType,
+    /// 시드 기반 랜덤 패치 (레이어보다 먼저 칠해짐)
+    #[serde(default)]
+    pub patches: Vec<GroundPatchDef>,
+    /// 손으로 그린 타일 레이어, 순서대로 위에 덮어씀
+    #[serde(default)]
+    pub layers: Vec<TileLayerDef>,
+    pub player_spawn: [i32; 2],
+    #[serde(default)]
+    pub decorations: Vec<DecorationDef>,
+    /// 고정 위치 자원
+    #[serde(default)]
+    pub resources: Vec<ResourceNodeDef>,
+    /// true면 자원 군집을 시드 기반으로 추가 배치
+    #[serde(default)]
+    pub scatter_resources: bool,
+    /// true면 서바이벌 웨이브가 이 맵에서 진행됨 (마을은 불가)
+    #[serde(default)]
+    pub survival_waves: bool,
+    #[serde(default)]
+    pub npcs: Vec<NpcSpawnDef>,
+    #[serde(default)]
+    pub enemy_zones: Vec<EnemyZoneDef>,
+    #[serde(default)]
+    pub portals: Vec<PortalDef>,
+}
+
+/// 문자 그림으로 그린 타일 레이어.
+/// `legend`에 없는 문자(공백 등)는 아래 타일을 그대로 둡니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TileLayerDef {
+    /// 첫 행 첫 문자가 놓일 타일
+    #[serde(default)]
+    pub origin: [i32; 2],
+    pub legend: BTreeMap<char, TileType>,
+    /// 위(-Z)에서 아래(+Z)로, 문자 하나 = 타일 하나
+    pub rows: Vec<String>,
+}
+
+/// 랜덤 위치에 둥글게 칠하는 타일 (연못, 흙길 등)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroundPatchDef {
+    pub tile: TileType,
+    pub count: u32,
+    /// 반지름 범위 (타일) `[min, max]`
+    pub radius: [f32; 2],
+    /// 패치 가장자리를 두르는 타일 (연못 둘레 모래 등)
+    #[serde(default)]
+    pub shore: Option<TileType>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecorationDef {
+    /// 에셋 경로 (`decorations/fountain.png`)
+    pub sprite: String,
+    /// 차지하는 영역의 -X/-Z 모서리 타일
+    pub tile: [i32; 2],
+    /// 차지하는 타일 수 `[x, y]`
+    #[serde(default = "default_footprint")]
+    pub footprint: [u32; 2],
+    /// 스프라이트 한 변의 크기 (월드 유닛)
+    #[serde(default = "default_decoration_size")]
+    pub size: f32,
+    /// 이동과 건설을 막는가
+    #[serde(default = "default_true")]
+    pub blocking: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceNodeDef {
+    pub resource: ResourceType,
+    pub tile: [i32; 2],
+    /// 생략하면 자원 종류 기본값
+    #[serde(default)]
+    pub amount: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpcSpawnDef {
+    pub id: String,
+    pub name: String,
+    /// 4x4 캐릭터 시트 (`npcs/merchant.png`)
+    pub sprite: String,
+    pub tile: [i32; 2],
+}
+
+/// 일정 수의 몬스터가 계속 유지되는 구역
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnemyZoneDef {
+    /// `shared::data::monsters` id 목록, 무작위로 선택
+    pub monsters: Vec<String>,
+    pub center: [i32; 2],
+    /// 반지름 (타일)
+    pub radius: f32,
+    pub max_alive: u32,
+    /// 한 마리가 죽은 뒤 다시 나타나기까지 (초)
+    pub respawn_secs: f32,
+}
+
+/// 밟으면 다른 맵으로 이동
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortalDef {
+    pub tile: [i32; 2],
+    pub target_map: String,
+    /// 도착 맵에서 나타날 타일
+    pub target_tile: [i32; 2],
+    /// 생략하면 `decorations/portal.png`
+    #[serde(default)]
+    pub sprite: Option<String>,
+}
+
+fn default_map_width() -> u32 {
+    MAP_WIDTH_DEFAULT
+}
+
+fn default_map_height() -> u32 {
+    MAP_HEIGHT_DEFAULT
+}
+
+fn default_footprint() -> [u32; 2] {
+    [1, 1]
+}
+
+fn default_decoration_size() -> f32 {
+    2.0
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// 맵 파일 검증 오류
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum MapFileError {
+    #[error("Map '{0}' has no tiles")]
+    Empty(String),
+    #[error("{what} at [{x}, {y}] is outside the map")]
+    OutOfBounds { what: &'static str, x: i32, y: i32 },
+    #[error("Player spawn [{x}, {y}] is not walkable")]
+    SpawnNotWalkable { x: i32, y: i32 },
+    #[error("Unknown monster '{0}'")]
+    UnknownMonster(String),
+    #[error("Enemy zone at [{x}, {y}] has no monsters")]
+    EmptyEnemyZone { x: i32, y: i32 },
+    #[error("Map '{0}' is a town (safe zone) but has monsters")]
+    MonstersInTown(String),
+    #[error("Portal target [{x}, {y}] on '{map}' is not walkable")]
+    ArrivalNotWalkable { map: String, x: i32, y: i32 },
+}
+
+impl MapFile {
+    /// 베이스 타일과 레이어만 칠한 맵 (랜덤 패치 제외)
+    pub fn base_tiles(&self) -> TileMap {
+        let mut tiles = TileMap::new(self.width, self.height, self.base_tile);
+        self.paint_layers(&mut tiles);
+        tiles
+    }
+
+    /// 랜덤 패치까지 칠한 맵. 같은 rng 상태면 항상 같은 결과.
+    pub fn build_tiles(&self, rng: &mut impl Rng) -> TileMap {
+        let mut tiles = TileMap::new(self.width, self.height, self.base_tile);
+        for patch in &self.patches {
+            self.paint_patch(&mut tiles, patch, rng);
+        }
+        self.paint_layers(&mut tiles);
+        tiles
+    }
+
+    pub fn validate(&self) -> Result<(), MapFileError> {
+        if self.width == 0 || self.height == 0 {
+            return Err(MapFileError::Empty(self.id.clone()));
+        }
+
+        let tiles = self.base_tiles();
+        let check = |what: &'static str, [x, y]: [i32; 2]| {
+            if tiles.in_bounds(x, y) {
+                Ok(())
+            } else {
+                Err(MapFileError::OutOfBounds { what, x, y })
+            }
+        };
+
+        check("Player spawn", self.player_spawn)?;
+        let [x, y] = self.player_spawn;
+        if !tiles.is_walkable(x, y) {
+            return Err(MapFileError::SpawnNotWalkable { x, y });
+        }
+        for decoration in &self.decorations {
+            check("Decoration", decoration.tile)?;
+            // 차지하는 영역의 +X/+Z 모서리 타일도 맵 안이어야 함
+            let [x, y] = decoration.tile;
+            let [width, height] = decoration.footprint.map(|size| size.max(1) as i32 - 1);
+            check("Decoration footprint", [x + width, y + height])?;
+        }
+        for resource in &self.resources {
+            check("Resource node", resource.tile)?;
+        }
+        for npc in &self.npcs {
+            check("NPC", npc.tile)?;
+        }
+        if (self.survival_waves || !self.enemy_zones.is_empty()) && self.is_town() {
+            return Err(MapFileError::MonstersInTown(self.id.clone()));
+        }
+        for zone in &self.enemy_zones {
+            check("Enemy zone", zone.center)?;
+            if zone.monsters.is_empty() {
+                return Err(MapFileError::EmptyEnemyZone { x: zone.center[0], y: zone.center[1] });
+            }
+            if let Some(unknown) = zone.monsters.iter().find(|id| monster_by_id(id).is_none()) {
+                return Err(MapFileError::UnknownMonster(unknown.clone()));
+            }
+        }
+        for portal in &self.portals {
+            check("Portal", portal.tile)?;
+            let [x, y] = portal.target_tile;
+            if x < 0 || y < 0 {
+                return Err(MapFileError::OutOfBounds { what: "Portal target", x, y });
+            }
+            // 다른 맵의 도착 타일은 그 맵이 로드될 때 확인 (걷지 못하면 시작 위치로)
+            if portal.target_map == self.id {
+                self.check_arrival(portal.target_tile)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 맵 정의에서 마을(안전 지대)로 분류된 맵인가
+    pub fn is_town(&self) -> bool {
+        map_by_id(&self.id).is_some_and(|map| map.kind == MapKind::Town)
+    }
+
+    /// 포탈로 도착할 타일이 이 맵 안의 걸을 수 있는 칸인지 확인
+    pub fn check_arrival(&self, [x, y]: [i32; 2]) -> Result<(), MapFileError> {
+        let tiles = self.base_tiles();
+        if !tiles.in_bounds(x, y) {
+            return Err(MapFileError::OutOfBounds { what: "Portal target", x, y });
+        }
+        if !tiles.is_walkable(x, y) {
+            return Err(MapFileError::ArrivalNotWalkable { map: self.id.clone(), x, y });
+        }
+        Ok(())
+    }
+
+    fn paint_layers(&self, tiles: &mut TileMap) {
+        for layer in &self.layers {
+            for (row, line) in layer.rows.iter().enumerate() {
+                for (column, symbol) in line.chars().enumerate() {
+                    if let Some(tile) = layer.legend.get(&symbol) {
+                        tiles.set(layer.origin[0] + column as i32, layer.origin[1] + row as i32, *tile);
+                    }
+                }
+            }
+        }
+    }
+
+    fn paint_patch(&self, tiles: &mut TileMap, patch: &GroundPatchDef, rng: &mut impl Rng) {
+        let [spawn_x, spawn_y] = self.player_spawn;
+        let near_spawn = |x: i32, y: i32| (x - spawn_x).abs().max((y - spawn_y).abs()) <= SPAWN_CLEAR_RADIUS;
+        let [min_radius, max_radius] = patch.radius;
+
+        for _ in 0..patch.count {
+            let center_x = rng.gen_range(0..self.width as i32);
+            let center_y = rng.gen_range(0..self.height as i32);
+            let radius = rng.gen_range(min_radius..=max_radius.max(min_radius));
+            // One extra ring for the shore
+            let reach = radius.ceil() as i32 + 1;
+            for dy in -reach..=reach {
+                for dx in -reach..=reach {
+                    let (x, y) = (center_x + dx, center_y + dy);
+                    if near_spawn(x, y) {
+                        continue;
+                    }
+                    // Jitter the edge so patches aren't perfect circles
+                    let edge = radius + rng.gen_range(-0.5..0.5);
+                    let distance = ((dx * dx + dy * dy) as f32).sqrt();
+                    if distance <= edge {
+                        tiles.set(x, y, patch.tile);
+                    } else if let Some(shore) = patch.shore
+                        && distance <= edge + 1.0
+                        && tiles.get(x, y) != Some(patch.tile)
+                    {
+                        tiles.set(x, y, shore);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,7 @@
 //! Shared module - used by both client and server
 
 pub mod constants;
+pub mod data;
 pub mod domain;
+pub mod map_file;
+pub mod tilemap;
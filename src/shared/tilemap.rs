@@ -0,0 +1,172 @@
+//! Tile Map - 타일 맵 그리드
+//!
+//! 바닥 타일 종류, 타일별 통행 가능 여부, 월드 ↔ 타일 좌표 변환.
+//! 맵은 XZ 평면에 놓이며, 타일 (0, 0)의 -X/-Z 모서리가 월드 원점입니다.
+//! 타일 1칸 = `TILE_WORLD_SIZE` 월드 유닛이므로 좌표 변환은 맵 크기와 무관합니다.
+
+use serde::{Deserialize, Serialize};
+use crate::shared::constants::TILE_WORLD_SIZE;
+
+/// 바닥 타일 종류 (`public/assets/tiles/*.png`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TileType {
+    #[default]
+    Grass,
+    Dirt,
+    Sand,
+    Stone,
+    Ice,
+    Bridge,
+    Carpet,
+    Dungeon,
+    LavaRock,
+    Water,
+    Lava,
+    Wall,
+}
+
+impl TileType {
+    pub const ALL: [TileType; 12] = [
+        TileType::Grass,
+        TileType::Dirt,
+        TileType::Sand,
+        TileType::Stone,
+        TileType::Ice,
+        TileType::Bridge,
+        TileType::Carpet,
+        TileType::Dungeon,
+        TileType::LavaRock,
+        TileType::Water,
+        TileType::Lava,
+        TileType::Wall,
+    ];
+
+    /// 텍스처 경로 (assets 기준)
+    pub const fn texture(self) -> &'static str {
+        match self {
+            TileType::Grass => "tiles/grass.png",
+            TileType::Dirt => "tiles/dirt.png",
+            TileType::Sand => "tiles/sand.png",
+            TileType::Stone => "tiles/stone.png",
+            TileType::Ice => "tiles/ice.png",
+            TileType::Bridge => "tiles/bridge.png",
+            TileType::Carpet => "tiles/carpet.png",
+            TileType::Dungeon => "tiles/dungeon.png",
+            TileType::LavaRock => "tiles/lava_rock.png",
+            TileType::Water => "tiles/water.png",
+            TileType::Lava => "tiles/lava.png",
+            TileType::Wall => "tiles/wall.png",
+        }
+    }
+
+    /// 캐릭터/몬스터가 지나갈 수 있는가 (물, 용암, 벽은 불가)
+    pub const fn is_walkable(self) -> bool {
+        !matches!(self, TileType::Water | TileType::Lava | TileType::Wall)
+    }
+}
+
+/// 월드 좌표 (x, z) 아래의 타일 (맵 밖일 수 있음)
+pub fn world_to_tile(x: f32, z: f32) -> (i32, i32) {
+    ((x / TILE_WORLD_SIZE).floor() as i32, (z / TILE_WORLD_SIZE).floor() as i32)
+}
+
+/// 타일의 -X/-Z 모서리 월드 좌표 (x, z)
+pub fn tile_to_world(tile_x: i32, tile_y: i32) -> (f32, f32) {
+    (tile_x as f32 * TILE_WORLD_SIZE, tile_y as f32 * TILE_WORLD_SIZE)
+}
+
+/// 타일 중심의 월드 좌표 (x, z)
+pub fn tile_center_to_world(tile_x: i32, tile_y: i32) -> (f32, f32) {
+    let (x, z) = tile_to_world(tile_x, tile_y);
+    (x + TILE_WORLD_SIZE * 0.5, z + TILE_WORLD_SIZE * 0.5)
+}
+
+/// 바닥 타일 그리드 (행 우선, y * width + x)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TileMap {
+    width: u32,
+    height: u32,
+    tiles: Vec<TileType>,
+}
+
+impl TileMap {
+    /// `fill` 타일로 채운 맵
+    pub fn new(width: u32, height: u32, fill: TileType) -> Self {
+        Self {
+            width,
+            height,
+            tiles: vec![fill; (width * height) as usize],
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn in_bounds(&self, x: i32, y: i32) -> bool {
+        x >= 0 && y >= 0 && (x as u32) < self.width && (y as u32) < self.height
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> Option<TileType> {
+        self.index(x, y).map(|index| self.tiles[index])
+    }
+
+    /// 맵 밖이면 false
+    pub fn set(&mut self, x: i32, y: i32, tile: TileType) -> bool {
+        match self.index(x, y) {
+            Some(index) => {
+                self.tiles[index] = tile;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 맵 밖은 통행 불가
+    pub fn is_walkable(&self, x: i32, y: i32) -> bool {
+        self.get(x, y).is_some_and(TileType::is_walkable)
+    }
+
+    /// 모든 타일 (x, y, 종류)
+    pub fn iter(&self) -> impl Iterator<Item = (i32, i32, TileType)> + '_ {
+        self.tiles.iter().enumerate().map(|(index, tile)| {
+            let index = index as u32;
+            ((index % self.width) as i32, (index / self.width) as i32, *tile)
+        })
+    }
+
+    /// 맵 전체 크기 (월드 유닛)
+    pub fn world_size(&self) -> (f32, f32) {
+        (self.width as f32 * TILE_WORLD_SIZE, self.height as f32 * TILE_WORLD_SIZE)
+    }
+
+    /// 월드 좌표 (x, z) 아래의 타일 (맵 밖일 수 있음)
+    pub fn world_to_tile(&self, x: f32, z: f32) -> (i32, i32) {
+        world_to_tile(x, z)
+    }
+
+    /// 타일의 -X/-Z 모서리 월드 좌표 (x, z)
+    pub fn tile_to_world(&self, x: i32, y: i32) -> (f32, f32) {
+        tile_to_world(x, y)
+    }
+
+    /// 타일 중심의 월드 좌표 (x, z)
+    pub fn tile_center_to_world(&self, x: i32, y: i32) -> (f32, f32) {
+        tile_center_to_world(x, y)
+    }
+
+    /// 맵 중심의 월드 좌표 (x, z)
+    pub fn world_center(&self) -> (f32, f32) {
+        let (width, height) = self.world_size();
+        (width * 0.5, height * 0.5)
+    }
+
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        self.in_bounds(x, y).then(|| y as usize * self.width as usize + x as usize)
+    }
+}
@@ -0,0 +1,90 @@
+use chrono::Utc;
+use legend_client::shared::domain::DomainError;
+use legend_client::shared::domain::inventory::{EquipSlot, InventoryItem, PaperDollLayer};
+use uuid::Uuid;
+
+#[test]
+fn visible_slots_draw_on_their_own_layer() {
+    let expected = [
+        (EquipSlot::Weapon, Some(PaperDollLayer::Weapon)),
+        (EquipSlot::Shield, Some(PaperDollLayer::Shield)),
+        (EquipSlot::Helmet, Some(PaperDollLayer::Helmet)),
+        (EquipSlot::Armor, Some(PaperDollLayer::Armor)),
+        (EquipSlot::Pants, Some(PaperDollLayer::Pants)),
+        (EquipSlot::Cape, Some(PaperDollLayer::Cape)),
+        (EquipSlot::Gloves, None),
+        (EquipSlot::Boots, None),
+        (EquipSlot::Belt, None),
+        (EquipSlot::Ring, None),
+        (EquipSlot::Amulet, None),
+    ];
+    assert_eq!(expected.len(), EquipSlot::ALL.len());
+    for (slot, layer) in expected {
+        assert_eq!(slot.paper_doll_layer(), layer, "{slot:?}");
+    }
+}
+
+#[test]
+fn layer_order_matches_assets_md() {
+    let order = [
+        PaperDollLayer::Shadow,
+        PaperDollLayer::Body,
+        PaperDollLayer::Pants,
+        PaperDollLayer::Armor,
+        PaperDollLayer::Hair,
+        PaperDollLayer::Helmet,
+        PaperDollLayer::Weapon,
+        PaperDollLayer::Shield,
+        PaperDollLayer::Cape,
+        PaperDollLayer::Effect,
+    ];
+    for (index, layer) in order.into_iter().enumerate() {
+        assert_eq!(layer.z_index(), index, "{layer:?}");
+    }
+    // Every equippable layer has sheets to load
+    for slot in EquipSlot::ALL {
+        if let Some(layer) = slot.paper_doll_layer() {
+            assert!(layer.asset_dir().is_some(), "{layer:?}");
+        }
+    }
+}
+
+#[test]
+fn slots_parse_from_their_names() {
+    for slot in EquipSlot::ALL {
+        assert_eq!(EquipSlot::parse(slot.as_str()), Ok(slot));
+    }
+    assert_eq!(
+        EquipSlot::parse("tail"),
+        Err(DomainError::Unknown { what: "equip slot", value: "tail".to_string() })
+    );
+}
+
+#[test]
+fn items_need_a_positive_quantity() {
+    let character = Uuid::new_v4();
+    assert_eq!(InventoryItem::new(character, 1, 0, Utc::now()), Err(DomainError::Quantity(0)));
+
+    let mut item = InventoryItem::new(character, 1, 3, Utc::now()).unwrap();
+    assert_eq!(item.quantity, 3);
+    assert_eq!(item.set_quantity(-1), Err(DomainError::Quantity(-1)));
+    assert_eq!(item.quantity, 3, "rejected changes leave the item alone");
+}
+
+#[test]
+fn enhancement_cannot_go_negative() {
+    let mut item = InventoryItem::new(Uuid::new_v4(), 1, 1, Utc::now()).unwrap();
+    assert_eq!(item.set_enhancement_level(-1), Err(DomainError::Enhancement(-1)));
+    item.set_enhancement_level(7).unwrap();
+    assert_eq!(item.enhancement_level, 7);
+}
+
+#[test]
+fn equipping_sets_the_slot() {
+    let mut item = InventoryItem::new(Uuid::new_v4(), 1, 1, Utc::now()).unwrap();
+    assert!(!item.is_equipped());
+    item.equip(EquipSlot::Weapon);
+    assert_eq!(item.equipped_slot, Some(EquipSlot::Weapon));
+    item.unequip();
+    assert!(!item.is_equipped());
+}
@@ -0,0 +1,56 @@
+use chrono::Utc;
+use legend_client::shared::domain::DomainError;
+use legend_client::shared::domain::dungeon::DungeonProgress;
+use legend_client::shared::domain::skill::{CharacterSkill, SKILL_SLOT_COUNT, SkillSlot};
+use std::time::Duration;
+use uuid::Uuid;
+
+#[test]
+fn skill_slots_are_one_to_five() {
+    assert_eq!(SkillSlot::new(0), Err(DomainError::SkillSlot(0)));
+    assert_eq!(SkillSlot::new(SKILL_SLOT_COUNT as i32 + 1), Err(DomainError::SkillSlot(6)));
+
+    let slot = SkillSlot::new(3).unwrap();
+    assert_eq!(slot.get(), 3);
+    assert_eq!(slot.index(), 2);
+}
+
+#[test]
+fn skill_slots_are_checked_when_deserialized() {
+    assert_eq!(serde_json::from_str::<SkillSlot>("5").unwrap(), SkillSlot::new(5).unwrap());
+    assert!(serde_json::from_str::<SkillSlot>("9").is_err());
+    assert_eq!(serde_json::to_string(&SkillSlot::new(2).unwrap()).unwrap(), "2");
+}
+
+#[test]
+fn skills_start_at_level_one() {
+    let mut skill = CharacterSkill::learn(Uuid::new_v4(), 101, Utc::now());
+    assert_eq!(skill.skill_level, 1);
+    assert_eq!(skill.slot, None);
+
+    assert_eq!(skill.set_level(0), Err(DomainError::SkillLevel(0)));
+    assert_eq!(skill.skill_level, 1);
+    skill.set_level(4).unwrap();
+    assert_eq!(skill.skill_level, 4);
+}
+
+#[test]
+fn dungeon_clears_keep_the_best_time() {
+    let mut progress = DungeonProgress::new(Uuid::new_v4(), 1);
+    assert_eq!((progress.clear_count, progress.best_clear_time_ms), (0, None));
+
+    assert!(progress.record_clear(Duration::from_secs(300)));
+    assert!(!progress.record_clear(Duration::from_secs(400)));
+    assert!(progress.record_clear(Duration::from_millis(250_500)));
+
+    assert_eq!(progress.clear_count, 3);
+    assert_eq!(progress.best_clear_time_ms, Some(250_500));
+}
+
+#[test]
+fn entering_records_the_time() {
+    let mut progress = DungeonProgress::new(Uuid::new_v4(), 2);
+    let now = Utc::now();
+    progress.enter(now);
+    assert_eq!(progress.last_entered_at, Some(now));
+}
@@ -0,0 +1,49 @@
+use chrono::Utc;
+use legend_client::shared::domain::DomainError;
+use legend_client::shared::domain::character::{Character, CharacterClass, Gender, Stats};
+
+fn character_with_points(stat_points: i32) -> Character {
+    let mut character = Character::new("Tester", CharacterClass::Warrior, Gender::Male, Utc::now()).unwrap();
+    character.stat_points = stat_points;
+    character
+}
+
+#[test]
+fn spending_moves_points_into_bonus_stats() {
+    let mut character = character_with_points(5);
+    character.spend_stat_points(Stats::new(2, 0, 0, 0, 1)).unwrap();
+
+    assert_eq!(character.bonus_stats, Stats::new(2, 0, 0, 0, 1));
+    assert_eq!(character.stat_points, 2);
+}
+
+#[test]
+fn spending_more_than_available_is_rejected() {
+    let mut character = character_with_points(2);
+    let result = character.spend_stat_points(Stats::new(1, 1, 1, 0, 0));
+
+    assert_eq!(result, Err(DomainError::NotEnoughStatPoints { needed: 3, available: 2 }));
+    assert_eq!(character.stat_points, 2);
+}
+
+#[test]
+fn overflowing_totals_are_rejected_not_wrapped() {
+    let mut character = character_with_points(10);
+    let huge = Stats::new(i32::MAX, i32::MAX, 0, 0, 0);
+
+    assert_eq!(huge.total(), Err(DomainError::StatOverflow));
+    assert_eq!(character.spend_stat_points(huge), Err(DomainError::StatOverflow));
+    assert_eq!(character.bonus_stats, Stats::ZERO);
+    assert_eq!(character.stat_points, 10);
+}
+
+#[test]
+fn overflowing_bonus_stats_leave_the_character_unchanged() {
+    let mut character = character_with_points(i32::MAX);
+    character.bonus_stats = Stats::new(i32::MAX, 0, 0, 0, 0);
+
+    assert_eq!(character.spend_stat_points(Stats::new(1, 0, 0, 0, 0)), Err(DomainError::StatOverflow));
+    assert_eq!(character.bonus_stats, Stats::new(i32::MAX, 0, 0, 0, 0));
+    assert_eq!(character.stat_points, i32::MAX);
+    assert_eq!(Stats::new(1, 2, 3, 4, 5).checked_add(Stats::new(1, 1, 1, 1, 1)), Ok(Stats::new(2, 3, 4, 5, 6)));
+}
@@ -0,0 +1,198 @@
+use std::path::Path;
+
+use legend_client::shared::constants::{MAP_HEIGHT_DEFAULT, MAP_WIDTH_DEFAULT};
+use legend_client::shared::map_file::{MapFile, MapFileError, SPAWN_CLEAR_RADIUS};
+use legend_client::shared::tilemap::TileType;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use serde_json::{Value, json};
+
+/// 10x8 grass map with the spawn in the middle, plus `extra` fields
+fn map(extra: Value) -> MapFile {
+    let mut json = json!({
+        "id": "test",
+        "name": "Test",
+        "width": 10,
+        "height": 8,
+        "player_spawn": [5, 4],
+    });
+    json.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+    serde_json::from_value(json).unwrap()
+}
+
+fn shipped_maps() -> Vec<MapFile> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("public/assets/maps");
+    let mut maps = Vec::new();
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().is_some_and(|extension| extension == "json") {
+            let text = std::fs::read_to_string(&path).unwrap();
+            let map: MapFile = serde_json::from_str(&text).unwrap_or_else(|error| panic!("{}: {error}", path.display()));
+            assert_eq!(Some(map.id.as_str()), path.file_stem().and_then(|stem| stem.to_str()), "id must match the file name");
+            maps.push(map);
+        }
+    }
+    maps
+}
+
+#[test]
+fn shipped_maps_are_valid() {
+    let maps = shipped_maps();
+    assert!(!maps.is_empty());
+    for map in &maps {
+        assert_eq!(map.validate(), Ok(()), "{}", map.id);
+    }
+}
+
+#[test]
+fn minimal_map_is_valid() {
+    assert_eq!(map(json!({})).validate(), Ok(()));
+}
+
+#[test]
+fn spawn_outside_the_map_is_rejected() {
+    let map = map(json!({ "player_spawn": [10, 2] }));
+    assert_eq!(map.validate(), Err(MapFileError::OutOfBounds { what: "Player spawn", x: 10, y: 2 }));
+}
+
+#[test]
+fn spawn_on_water_is_rejected() {
+    let map = map(json!({ "layers": [{ "origin": [5, 4], "legend": { "~": "water" }, "rows": ["~"] }] }));
+    assert_eq!(map.validate(), Err(MapFileError::SpawnNotWalkable { x: 5, y: 4 }));
+}
+
+#[test]
+fn unknown_monster_is_rejected() {
+    let map = map(json!({
+        "enemy_zones": [{ "monsters": ["rat", "unicorn"], "center": [2, 2], "radius": 2.0, "max_alive": 3, "respawn_secs": 5.0 }]
+    }));
+    assert_eq!(map.validate(), Err(MapFileError::UnknownMonster("unicorn".to_string())));
+}
+
+#[test]
+fn empty_enemy_zone_is_rejected() {
+    let map = map(json!({
+        "enemy_zones": [{ "monsters": [], "center": [2, 3], "radius": 2.0, "max_alive": 3, "respawn_secs": 5.0 }]
+    }));
+    assert_eq!(map.validate(), Err(MapFileError::EmptyEnemyZone { x: 2, y: 3 }));
+}
+
+#[test]
+fn decoration_footprint_must_fit_on_the_map() {
+    let fits = map(json!({ "decorations": [{ "sprite": "buildings/house.png", "tile": [8, 6], "footprint": [2, 2] }] }));
+    assert_eq!(fits.validate(), Ok(()));
+
+    let oversize = map(json!({ "decorations": [{ "sprite": "buildings/house.png", "tile": [8, 6], "footprint": [3, 2] }] }));
+    assert_eq!(oversize.validate(), Err(MapFileError::OutOfBounds { what: "Decoration footprint", x: 10, y: 7 }));
+}
+
+#[test]
+fn layers_paint_over_the_base_tile() {
+    let map = map(json!({
+        "base_tile": "sand",
+        "layers": [{ "origin": [1, 1], "legend": { "#": "stone" }, "rows": ["# #", " #"] }]
+    }));
+    let tiles = map.base_tiles();
+
+    assert_eq!(tiles.get(0, 0), Some(TileType::Sand));
+    assert_eq!(tiles.get(1, 1), Some(TileType::Stone));
+    assert_eq!(tiles.get(2, 1), Some(TileType::Sand), "spaces keep the tile below");
+    assert_eq!(tiles.get(3, 1), Some(TileType::Stone));
+    assert_eq!(tiles.get(2, 2), Some(TileType::Stone));
+}
+
+fn patched_map() -> MapFile {
+    let mut map = map(json!({
+        "patches": [{ "tile": "water", "count": 12, "radius": [2.0, 4.0], "shore": "sand" }]
+    }));
+    map.width = 40;
+    map.height = 40;
+    map.player_spawn = [20, 20];
+    map
+}
+
+#[test]
+fn same_seed_builds_the_same_tiles() {
+    let map = patched_map();
+    let build = |seed| map.build_tiles(&mut SmallRng::seed_from_u64(seed));
+
+    assert_eq!(build(7), build(7));
+    assert_ne!(build(7), build(8));
+}
+
+#[test]
+fn patches_never_paint_around_the_spawn() {
+    let map = patched_map();
+    let [spawn_x, spawn_y] = map.player_spawn;
+    let mut painted = 0;
+    for seed in 0..20 {
+        let tiles = map.build_tiles(&mut SmallRng::seed_from_u64(seed));
+        for (x, y, tile) in tiles.iter() {
+            if tile == TileType::Grass {
+                continue;
+            }
+            painted += 1;
+            let distance = (x - spawn_x).abs().max((y - spawn_y).abs());
+            assert!(distance > SPAWN_CLEAR_RADIUS, "seed {seed}: {tile:?} at ({x}, {y}) next to the spawn");
+        }
+    }
+    assert!(painted > 0, "patches should paint something");
+}
+
+#[test]
+fn shipped_portals_arrive_on_walkable_tiles() {
+    let maps = shipped_maps();
+    for map in &maps {
+        for portal in &map.portals {
+            let target = maps
+                .iter()
+                .find(|candidate| candidate.id == portal.target_map)
+                .unwrap_or_else(|| panic!("{}: portal to unknown map '{}'", map.id, portal.target_map));
+            assert_eq!(target.check_arrival(portal.target_tile), Ok(()), "{} -> {}", map.id, target.id);
+        }
+    }
+}
+
+#[test]
+fn portal_target_must_be_checkable() {
+    let portal = |target_map: &str, target_tile: [i32; 2]| {
+        map(json!({ "portals": [{ "tile": [0, 0], "target_map": target_map, "target_tile": target_tile }] }))
+    };
+
+    assert_eq!(portal("elsewhere", [40, 40]).validate(), Ok(()), "other maps are checked on arrival");
+    assert_eq!(
+        portal("elsewhere", [-1, 3]).validate(),
+        Err(MapFileError::OutOfBounds { what: "Portal target", x: -1, y: 3 })
+    );
+    assert_eq!(
+        portal("test", [10, 3]).validate(),
+        Err(MapFileError::OutOfBounds { what: "Portal target", x: 10, y: 3 })
+    );
+
+    let mut onto_water = portal("test", [2, 2]);
+    onto_water.layers = map(json!({ "layers": [{ "origin": [2, 2], "legend": { "~": "water" }, "rows": ["~"] }] })).layers;
+    assert_eq!(
+        onto_water.validate(),
+        Err(MapFileError::ArrivalNotWalkable { map: "test".to_string(), x: 2, y: 2 })
+    );
+}
+
+#[test]
+fn towns_cannot_run_survival_waves() {
+    let town = map(json!({ "id": "village_milles", "survival_waves": true }));
+    assert_eq!(town.validate(), Err(MapFileError::MonstersInTown("village_milles".to_string())));
+
+    let zone = json!([{ "monsters": ["rat"], "center": [2, 2], "radius": 2.0, "max_alive": 3, "respawn_secs": 5.0 }]);
+    let town = map(json!({ "id": "village_milles", "enemy_zones": zone }));
+    assert_eq!(town.validate(), Err(MapFileError::MonstersInTown("village_milles".to_string())));
+
+    let field = map(json!({ "id": "milles_plains", "survival_waves": true }));
+    assert_eq!(field.validate(), Ok(()));
+}
+
+#[test]
+fn size_defaults_when_omitted() {
+    let map: MapFile = serde_json::from_value(json!({ "id": "test", "name": "Test", "player_spawn": [1, 1] })).unwrap();
+    assert_eq!((map.width, map.height), (MAP_WIDTH_DEFAULT, MAP_HEIGHT_DEFAULT));
+    assert_eq!((map.base_tiles().width(), map.base_tiles().height()), (MAP_WIDTH_DEFAULT, MAP_HEIGHT_DEFAULT));
+}
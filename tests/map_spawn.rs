@@ -0,0 +1,89 @@
+#![cfg(feature = "client")]
+
+use bevy::prelude::*;
+use legend_client::client::animation::AnimationPlugin;
+use legend_client::client::building::TileOccupancy;
+use legend_client::client::enemy::MonsterAssets;
+use legend_client::client::map::{ActiveMap, MapAsset, MapPlugin};
+use legend_client::client::seed::MapSeed;
+use legend_client::client::tilemap::GroundTiles;
+use legend_client::client::wave::{WaveManager, WavePlugin};
+use legend_client::shared::map_file::MapFile;
+use serde_json::json;
+
+/// Map and wave plugins with the render assets and resources their systems read
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Mesh>()
+        .init_asset::<StandardMaterial>()
+        .init_asset::<Image>()
+        .init_resource::<TileOccupancy>()
+        .init_resource::<MonsterAssets>()
+        .insert_resource(MapSeed(1))
+        .add_plugins((AnimationPlugin, MapPlugin, WavePlugin));
+    app.update();
+    app
+}
+
+/// Make `map` the active map, as if it had just loaded
+fn enter(app: &mut App, map: MapFile) {
+    let mut active_map = ActiveMap::load(&map.id, app.world().resource::<AssetServer>());
+    active_map.handle = app.world_mut().resource_mut::<Assets<MapAsset>>().add(MapAsset(map));
+    app.insert_resource(active_map);
+    app.update();
+}
+
+fn map(player_spawn: [i32; 2]) -> MapFile {
+    map_with(json!({ "player_spawn": player_spawn }))
+}
+
+/// 10x8 grass map with the spawn in the middle, plus `extra` fields
+fn map_with(extra: serde_json::Value) -> MapFile {
+    let mut json = json!({
+        "id": "test",
+        "name": "Test",
+        "width": 10,
+        "height": 8,
+        "player_spawn": [5, 4],
+    });
+    json.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+    serde_json::from_value(json).unwrap()
+}
+
+#[test]
+fn valid_map_is_spawned() {
+    let mut app = app();
+    enter(&mut app, map([5, 4]));
+
+    let active_map = app.world().resource::<ActiveMap>();
+    assert!(active_map.is_spawned());
+    assert!(!active_map.has_failed());
+    assert!(app.world().get_resource::<GroundTiles>().is_some());
+}
+
+#[test]
+fn invalid_map_fails_without_spawning() {
+    let mut app = app();
+    enter(&mut app, map([20, 4]));
+
+    let active_map = app.world().resource::<ActiveMap>();
+    assert!(active_map.has_failed());
+    assert!(!active_map.is_spawned());
+    assert!(app.world().get_resource::<GroundTiles>().is_none());
+}
+
+#[test]
+fn waves_only_count_down_on_survival_maps() {
+    let mut app = app();
+    assert!(app.world().resource::<WaveManager>().is_paused(), "nothing runs before a map is spawned");
+
+    enter(&mut app, map_with(json!({ "id": "village_milles" })));
+    app.update();
+    assert!(app.world().resource::<WaveManager>().is_paused());
+    assert_eq!(app.world().resource::<WaveManager>().next_wave_in(), None);
+
+    enter(&mut app, map_with(json!({ "survival_waves": true })));
+    app.update();
+    assert!(!app.world().resource::<WaveManager>().is_paused());
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "client")]
+
+use bevy::ecs::entity::Entity;
+use bevy::math::{IVec2, Vec2, Vec3};
+use legend_client::client::navigation::{NAV_CELL_SIZE, NavGrid};
+use legend_client::client::tilemap::GroundTiles;
+use legend_client::shared::tilemap::{TileMap, TileType, tile_center_to_world};
+
+/// 10x10 grid of 1-unit cells
+fn open_grid() -> NavGrid {
+    NavGrid::new(Vec2::ZERO, Vec2::splat(10.0), 1.0)
+}
+
+fn center(x: i32, y: i32) -> Vec3 {
+    Vec3::new(x as f32 + 0.5, 0.0, y as f32 + 0.5)
+}
+
+fn assert_heading(actual: Option<Vec3>, expected: Vec3) {
+    let actual = actual.expect("no flow direction");
+    assert!(actual.distance(expected.normalize()) < 1e-4, "heading {actual}, expected {expected}");
+}
+
+#[test]
+fn flow_points_toward_the_target() {
+    let mut grid = open_grid();
+    grid.update_targets([center(8, 5)], []);
+
+    assert_heading(grid.flow_direction(center(2, 5)), Vec3::X);
+    assert_heading(grid.flow_direction(center(8, 1)), Vec3::Z);
+    assert_heading(grid.flow_direction(center(5, 2)), Vec3::new(1.0, 0.0, 1.0));
+    assert_eq!(grid.flow_direction(center(8, 5)), None, "already on the target");
+}
+
+#[test]
+fn diagonal_steps_do_not_cut_blocked_corners() {
+    let mut grid = open_grid();
+    grid.add_obstacle(Entity::from_raw(1), center(3, 2), Vec2::ONE);
+    grid.update_targets([center(3, 3)], []);
+
+    assert!(!grid.is_walkable(IVec2::new(3, 2)));
+    // The diagonal would clip the obstacle's corner, so go round through (2, 3)
+    assert_heading(grid.flow_direction(center(2, 2)), Vec3::Z);
+}
+
+#[test]
+fn removing_an_obstacle_reopens_its_cells() {
+    let mut grid = open_grid();
+    let wall = Entity::from_raw(1);
+    grid.add_obstacle(wall, Vec3::new(5.0, 0.0, 5.0), Vec2::new(2.0, 2.0));
+    for cell in [IVec2::new(4, 4), IVec2::new(5, 4), IVec2::new(4, 5), IVec2::new(5, 5)] {
+        assert!(!grid.is_walkable(cell), "{cell} should be blocked");
+    }
+    assert!(grid.is_walkable(IVec2::new(3, 4)), "flush neighbour must stay open");
+
+    grid.remove_obstacle(wall);
+    assert!(grid.is_walkable(IVec2::new(4, 4)));
+}
+
+#[test]
+fn enemies_stop_at_the_edge_of_a_building() {
+    let mut grid = open_grid();
+    let building = Entity::from_raw(1);
+    grid.add_obstacle(building, Vec3::new(5.0, 0.0, 5.0), Vec2::new(2.0, 2.0));
+    grid.update_targets([], [building]);
+
+    assert_heading(grid.flow_direction(center(1, 4)), Vec3::X);
+    // Next to the footprint is the goal: nothing leads into it
+    assert_eq!(grid.flow_direction(center(3, 4)), None);
+    assert_eq!(grid.flow_direction(center(6, 6)), None);
+    for y in 0..10 {
+        for x in 0..10 {
+            let Some(heading) = grid.flow_direction(center(x, y)) else {
+                continue;
+            };
+            let next = grid.cell_at(center(x, y) + heading).unwrap();
+            assert!(grid.is_walkable(next), "({x}, {y}) is led into blocked {next}");
+        }
+    }
+}
+
+#[test]
+fn enclosed_target_is_unreachable() {
+    let mut grid = open_grid();
+    for (index, x) in (0..10).enumerate() {
+        grid.add_obstacle(Entity::from_raw(index as u32), center(x, 5), Vec2::ONE);
+    }
+    grid.update_targets([center(5, 8)], []);
+
+    assert_eq!(grid.flow_direction(center(5, 1)), None);
+    assert!(grid.flow_direction(center(1, 8)).is_some());
+}
+
+#[test]
+fn water_and_walls_are_blocked_terrain() {
+    let mut tiles = TileMap::new(6, 4, TileType::Grass);
+    tiles.set(2, 1, TileType::Water);
+    tiles.set(3, 1, TileType::Wall);
+    tiles.set(4, 1, TileType::Bridge);
+    let grid = NavGrid::for_ground(&GroundTiles(tiles));
+
+    let cell = |tile_x, tile_y| {
+        let (x, z) = tile_center_to_world(tile_x, tile_y);
+        // Tile centres sit on a cell corner; step into the tile's -X/-Z cell
+        grid.cell_at(Vec3::new(x - NAV_CELL_SIZE * 0.5, 0.0, z - NAV_CELL_SIZE * 0.5)).unwrap()
+    };
+    assert!(!grid.is_walkable(cell(2, 1)));
+    assert!(!grid.is_walkable(cell(3, 1)));
+    assert!(grid.is_walkable(cell(4, 1)));
+    assert!(grid.is_walkable(cell(0, 0)));
+    assert!(grid.cell_at(Vec3::new(-0.5, 0.0, 1.0)).is_none());
+}
+
+/// 30x30 grid with a wall across the middle (gap at the +X end)
+fn walled_grid() -> NavGrid {
+    let mut grid = NavGrid::new(Vec2::ZERO, Vec2::splat(30.0), 1.0);
+    for x in 0..26 {
+        grid.add_obstacle(Entity::from_raw(x as u32), center(x, 15), Vec2::ONE);
+    }
+    grid
+}
+
+fn assert_same_field(grid: &NavGrid, targets: &[Vec3]) {
+    let mut fresh = walled_grid();
+    fresh.update_targets(targets.iter().copied(), []);
+    for y in 0..30 {
+        for x in 0..30 {
+            let cell = IVec2::new(x, y);
+            assert_eq!(grid.distance_at(cell), fresh.distance_at(cell), "{cell} with targets {targets:?}");
+        }
+    }
+}
+
+#[test]
+fn moving_targets_matches_a_full_rebuild() {
+    let mut grid = walled_grid();
+    let steps = [
+        vec![center(5, 5)],
+        vec![center(6, 5)],
+        vec![center(6, 5), center(20, 25)],
+        vec![center(20, 25)],
+        vec![center(21, 25), center(2, 2)],
+        vec![center(28, 15)],
+        vec![],
+        vec![center(10, 10)],
+    ];
+    for targets in &steps {
+        grid.update_targets(targets.iter().copied(), []);
+        assert_same_field(&grid, targets);
+    }
+}
+
+#[test]
+fn a_target_far_away_only_touches_its_own_area() {
+    let mut grid = walled_grid();
+    grid.update_targets([center(20, 5)], []);
+    let whole_field = grid.cells_updated();
+    assert!(whole_field > 700, "first update fills the field, got {whole_field}");
+
+    // A target in the far corner only claims the cells closer to it
+    grid.update_targets([center(20, 5), center(1, 1)], []);
+    assert!(grid.cells_updated() < whole_field / 4, "updated {} cells", grid.cells_updated());
+
+    grid.update_targets([center(20, 5)], []);
+    assert!(grid.cells_updated() < whole_field / 4, "updated {} cells", grid.cells_updated());
+    assert_same_field(&grid, &[center(20, 5)]);
+}
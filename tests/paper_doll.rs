@@ -0,0 +1,56 @@
+#![cfg(feature = "client")]
+
+use bevy::math::Vec2;
+use legend_client::client::equipment::AnchorSet;
+
+fn anchor_set() -> AnchorSet {
+    serde_json::from_value(serde_json::json!({
+        "character_id": "test",
+        "frame_width": 48.0,
+        "frame_height": 64.0,
+        "animations": {
+            "idle_down": {
+                "state": "idle",
+                "direction": "down",
+                "frames": [{ "anchors": { "right_hand": { "x": 36.0, "y": 40.0 } } }]
+            },
+            "walk_down": {
+                "state": "walk",
+                "direction": "down",
+                "frames": [{ "anchors": { "right_hand": { "x": 39.0, "y": 38.0 } } }]
+            }
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn rest_frame_has_no_offset_either_way() {
+    let set = anchor_set();
+    let rest = set.rest().unwrap();
+    let size = Vec2::new(1.5, 2.0);
+
+    assert_eq!(set.layer_offset("right_hand", rest, false, size), Some(Vec2::ZERO));
+    assert_eq!(set.layer_offset("right_hand", rest, true, size), Some(Vec2::ZERO));
+}
+
+#[test]
+fn mirrored_offset_negates_x_for_off_centre_anchor() {
+    let set = anchor_set();
+    let frame = set.frame("walk", "down", 0).unwrap();
+    let size = Vec2::new(1.5, 2.0);
+
+    let plain = set.layer_offset("right_hand", frame, false, size).unwrap();
+    let mirrored = set.layer_offset("right_hand", frame, true, size).unwrap();
+
+    assert_eq!(plain, Vec2::new(3.0 * 1.5 / 48.0, 2.0 * 2.0 / 64.0));
+    assert_eq!(mirrored, Vec2::new(-plain.x, plain.y));
+}
+
+#[test]
+fn unknown_anchor_has_no_offset() {
+    let set = anchor_set();
+    let frame = set.frame("walk", "down", 0).unwrap();
+
+    assert_eq!(set.layer_offset("left_hand", frame, true, Vec2::ONE), None);
+}
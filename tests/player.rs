@@ -0,0 +1,58 @@
+#![cfg(feature = "client")]
+
+use bevy::prelude::*;
+use legend_client::client::animation::{AnimationFinished, SpriteAnimator, SpriteManifest};
+use legend_client::client::combat::{DamageEvent, DeathEvent};
+use legend_client::client::equipment::AnchorSet;
+use legend_client::client::game::GameResources;
+use legend_client::client::player::{MovementTarget, Player, PlayerPlugin, PlayerState};
+use legend_client::client::portal::MapTransition;
+
+/// Player plugin with just the resources and events its systems read
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<SpriteManifest>()
+        .init_asset::<AnchorSet>()
+        .init_resource::<ButtonInput<KeyCode>>()
+        .init_resource::<ButtonInput<MouseButton>>()
+        .init_resource::<GameResources>()
+        .init_resource::<MapTransition>()
+        .add_event::<DamageEvent>()
+        .add_event::<DeathEvent>()
+        .add_event::<AnimationFinished>()
+        .add_plugins(PlayerPlugin);
+    app.update();
+    app
+}
+
+fn player(app: &mut App) -> Entity {
+    app.world_mut().query_filtered::<Entity, With<Player>>().single(app.world())
+}
+
+fn animator_state(app: &mut App) -> String {
+    app.world_mut().query::<&SpriteAnimator>().single(app.world()).state.clone()
+}
+
+#[test]
+fn attacking_while_walking_keeps_the_swing() {
+    let mut app = app();
+    let player = player(&mut app);
+    app.world_mut().entity_mut(player).insert((MovementTarget(Vec3::new(20.0, 0.0, 0.0)), PlayerState::Moving));
+    app.update();
+    assert_eq!(animator_state(&mut app), "walk");
+
+    app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Space);
+    app.update();
+
+    assert_eq!(animator_state(&mut app), "attack");
+    assert!(matches!(app.world().get::<PlayerState>(player), Some(PlayerState::Attacking)));
+    assert!(app.world().get::<MovementTarget>(player).is_none(), "the swing stops the walk");
+
+    // Keep walking orders out of it: the next frame must not swap the swing for "walk"
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.release(KeyCode::Space);
+    keys.clear();
+    app.update();
+    assert_eq!(animator_state(&mut app), "attack");
+}
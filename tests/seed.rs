@@ -0,0 +1,64 @@
+#![cfg(feature = "client")]
+
+use legend_client::client::map::{ResourceSpawnConfig, scatter_resource_nodes};
+use legend_client::client::seed::MapSeed;
+use legend_client::shared::map_file::MapFile;
+use rand::Rng;
+use serde_json::json;
+
+/// 40x40 map with random ponds and scattered resources
+fn field(id: &str) -> MapFile {
+    serde_json::from_value(json!({
+        "id": id,
+        "name": "Field",
+        "width": 40,
+        "height": 40,
+        "player_spawn": [20, 20],
+        "patches": [{ "tile": "water", "count": 4, "radius": [1.5, 3.0], "shore": "sand" }],
+        "scatter_resources": true,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn same_seed_builds_the_same_map_whatever_was_rolled_before() {
+    let seed = MapSeed(42);
+    let map = field("field");
+    let config = ResourceSpawnConfig::default();
+
+    let mut rng = seed.map_rng(&map.id);
+    let tiles = map.build_tiles(&mut rng);
+    let nodes = scatter_resource_nodes(&map, &tiles, &config, &mut rng);
+    assert!(!nodes.is_empty());
+
+    // Waves and zones rolling in between must not shift the next visit's layout
+    let mut waves = seed.rng("waves/1/0");
+    let mut zone = seed.rng("zone/field/0");
+    for _ in 0..100 {
+        let _: u64 = waves.r#gen();
+        let _: f32 = zone.r#gen();
+    }
+
+    let mut rng = seed.map_rng(&map.id);
+    let again = map.build_tiles(&mut rng);
+    assert_eq!(again, tiles);
+    assert_eq!(scatter_resource_nodes(&map, &again, &config, &mut rng), nodes);
+}
+
+#[test]
+fn streams_differ_by_name_and_seed() {
+    let roll = |seed: u64, stream: &str| MapSeed(seed).rng(stream).r#gen::<u64>();
+
+    assert_eq!(roll(7, "map/a"), roll(7, "map/a"));
+    assert_ne!(roll(7, "map/a"), roll(7, "map/b"));
+    assert_ne!(roll(7, "map/a"), roll(8, "map/a"));
+}
+
+#[test]
+fn different_maps_get_different_layouts() {
+    let seed = MapSeed(42);
+    let first = field("first");
+    let second = field("second");
+
+    assert_ne!(first.build_tiles(&mut seed.map_rng(&first.id)), second.build_tiles(&mut seed.map_rng(&second.id)));
+}
@@ -0,0 +1,144 @@
+//! `/api/auth/register` and `/api/auth/login`. Everything past input validation
+//! needs a migrated database from `DATABASE_URL`.
+
+#![cfg(feature = "server")]
+
+mod common;
+
+use axum::http::{Method, StatusCode};
+use chrono::{DateTime, Utc};
+use legend_client::server::AppState;
+use legend_client::server::auth::JwtKeys;
+use legend_client::server::error::ErrorResponse;
+use legend_client::shared::domain::auth::{LoginRequest, LoginResponse, PASSWORD_MAX_LEN, RegisterRequest, UserResponse};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+fn register_request(username: &str) -> RegisterRequest {
+    RegisterRequest {
+        username: username.to_string(),
+        password: "correct horse".to_string(),
+        email: Some(format!("{username}@example.com")),
+    }
+}
+
+async fn register(state: &AppState, request: &RegisterRequest) -> common::TestResponse {
+    common::send(state, Method::POST, "/api/auth/register", Some(request), None).await
+}
+
+async fn login(state: &AppState, username: &str, password: &str) -> common::TestResponse {
+    let request = LoginRequest {
+        username: username.to_string(),
+        password: password.to_string(),
+    };
+    common::send(state, Method::POST, "/api/auth/login", Some(&request), None).await
+}
+
+async fn last_login(db: &PgPool, username: &str) -> Option<DateTime<Utc>> {
+    sqlx::query_scalar("SELECT last_login_at FROM users WHERE username = $1")
+        .bind(username)
+        .fetch_one(db)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn register_rejects_invalid_input() {
+    let state = common::unreachable_state();
+    let cases = [
+        RegisterRequest { username: "ab".into(), ..register_request("x") },
+        RegisterRequest { username: "has space".into(), ..register_request("x") },
+        RegisterRequest { password: "short".into(), ..register_request("valid_name") },
+        RegisterRequest { email: Some("not-an-email".into()), ..register_request("valid_name") },
+    ];
+    for request in &cases {
+        let response = register(&state, request).await;
+        assert_eq!(response.status, StatusCode::BAD_REQUEST, "{request:?}");
+        assert!(!response.json::<ErrorResponse>().error.is_empty());
+    }
+}
+
+#[tokio::test]
+async fn login_rejects_oversized_password_before_hashing() {
+    // Rejected before the database is touched, so the unreachable pool is never used
+    let state = common::unreachable_state();
+    let response = login(&state, "anyone", &"x".repeat(PASSWORD_MAX_LEN + 1)).await;
+    assert_eq!(response.status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn register_then_login() {
+    let db = common::database().await;
+    let state = AppState::new(db.clone(), common::JWT_SECRET);
+    let username = common::unique_name("hero");
+
+    let response = register(&state, &register_request(&username)).await;
+    assert_eq!(response.status, StatusCode::CREATED);
+    let user: UserResponse = response.json();
+    assert_eq!(user.username, username);
+    assert!(!response.body.windows(6).any(|window| window == b"argon2"), "hash must not leak");
+    assert_eq!(last_login(&db, &username).await, None);
+
+    let response = login(&state, &username, "correct horse").await;
+    assert_eq!(response.status, StatusCode::OK);
+    let session: LoginResponse = response.json();
+    assert_eq!(session.user, user);
+    let claims = state.jwt.verify(&session.token).unwrap();
+    assert_eq!(claims.sub, user.id);
+    assert_eq!(claims.exp, session.expires_at);
+    assert!(last_login(&db, &username).await.is_some());
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn register_rejects_taken_username() {
+    let db = common::database().await;
+    let state = AppState::new(db, common::JWT_SECRET);
+    let username = common::unique_name("twin");
+
+    assert_eq!(register(&state, &register_request(&username)).await.status, StatusCode::CREATED);
+    let response = register(&state, &register_request(&username)).await;
+    assert_eq!(response.status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn login_rejects_bad_credentials() {
+    let db = common::database().await;
+    let state = AppState::new(db.clone(), common::JWT_SECRET);
+    let username = common::unique_name("guard");
+    register(&state, &register_request(&username)).await;
+
+    let wrong_password = login(&state, &username, "wrong horse").await;
+    let unknown_user = login(&state, &common::unique_name("ghost"), "correct horse").await;
+    assert_eq!(wrong_password.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(unknown_user.status, StatusCode::UNAUTHORIZED);
+    // Same message either way so usernames can't be probed
+    assert_eq!(wrong_password.json::<ErrorResponse>(), unknown_user.json::<ErrorResponse>());
+    assert_eq!(last_login(&db, &username).await, None);
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn login_rejects_inactive_account() {
+    let db = common::database().await;
+    let state = AppState::new(db.clone(), common::JWT_SECRET);
+    let username = common::unique_name("banned");
+    register(&state, &register_request(&username)).await;
+    sqlx::query("UPDATE users SET is_active = FALSE WHERE username = $1")
+        .bind(&username)
+        .execute(&db)
+        .await
+        .unwrap();
+
+    let response = login(&state, &username, "correct horse").await;
+    assert_eq!(response.status, StatusCode::FORBIDDEN);
+    assert_eq!(last_login(&db, &username).await, None);
+}
+
+#[test]
+fn tokens_from_another_secret_are_rejected() {
+    let (token, _) = JwtKeys::new(b"other-secret").issue(Uuid::new_v4(), "mallory", Utc::now()).unwrap();
+    assert!(JwtKeys::new(common::JWT_SECRET).verify(&token).is_err());
+}
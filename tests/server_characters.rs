@@ -0,0 +1,146 @@
+//! `/api/characters` CRUD. Most tests need a migrated database from `DATABASE_URL`
+//! and are ignored unless run with `--include-ignored`.
+
+#![cfg(feature = "server")]
+
+mod common;
+
+use axum::http::{Method, StatusCode};
+use legend_client::server::AppState;
+use legend_client::shared::domain::character::{Character, CharacterClass, CreateCharacterRequest, Gender};
+use serde_json::json;
+
+async fn state() -> AppState {
+    AppState::new(common::database().await, common::JWT_SECRET)
+}
+
+/// Names are globally unique, so every test run needs fresh ones (max 12 chars)
+fn unique_character_name() -> String {
+    common::unique_name("c").replace('_', "")[..12].to_string()
+}
+
+async fn create(state: &AppState, token: &str, body: &impl serde::Serialize) -> common::TestResponse {
+    common::send(state, Method::POST, "/api/characters", Some(body), Some(token)).await
+}
+
+async fn call(state: &AppState, method: Method, uri: &str, token: &str) -> common::TestResponse {
+    common::send(state, method, uri, None::<&()>, Some(token)).await
+}
+
+#[tokio::test]
+async fn requires_login() {
+    let state = common::unreachable_state();
+    let response = common::get(&state, "/api/characters").await;
+    assert_eq!(response.status, StatusCode::UNAUTHORIZED);
+
+    let response = call(&state, Method::GET, "/api/characters", "not-a-token").await;
+    assert_eq!(response.status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn create_list_get_delete() {
+    let state = state().await;
+    let session = common::sign_up(&state).await;
+    let token = session.token.as_str();
+
+    let request = CreateCharacterRequest {
+        name: unique_character_name(),
+        class: CharacterClass::MartialArtist,
+        gender: Gender::Female,
+    };
+    let response = create(&state, token, &request).await;
+    assert_eq!(response.status, StatusCode::CREATED);
+    let character: Character = response.json();
+    assert_eq!(character.name, request.name);
+    assert_eq!(character.class, CharacterClass::MartialArtist);
+    assert_eq!(character.gender, Gender::Female);
+    assert_eq!(character.level, 1);
+    assert_eq!(character.current_map, "village_milles");
+
+    let response = call(&state, Method::GET, "/api/characters", token).await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.json::<Vec<Character>>(), vec![character.clone()]);
+
+    let uri = format!("/api/characters/{}", character.id);
+    let response = call(&state, Method::GET, &uri, token).await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.json::<Character>(), character);
+
+    assert_eq!(call(&state, Method::DELETE, &uri, token).await.status, StatusCode::NO_CONTENT);
+    assert_eq!(call(&state, Method::GET, &uri, token).await.status, StatusCode::NOT_FOUND);
+    assert_eq!(call(&state, Method::DELETE, &uri, token).await.status, StatusCode::NOT_FOUND);
+    let response = call(&state, Method::GET, "/api/characters", token).await;
+    assert!(response.json::<Vec<Character>>().is_empty());
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn names_are_unique_across_accounts() {
+    let state = state().await;
+    let first = common::sign_up(&state).await;
+    let second = common::sign_up(&state).await;
+    let request = CreateCharacterRequest {
+        name: unique_character_name(),
+        class: CharacterClass::Warrior,
+        gender: Gender::Male,
+    };
+
+    assert_eq!(create(&state, &first.token, &request).await.status, StatusCode::CREATED);
+    assert_eq!(create(&state, &second.token, &request).await.status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn rejects_invalid_requests() {
+    let state = state().await;
+    let session = common::sign_up(&state).await;
+    let bodies = [
+        json!({ "name": "a", "class": "warrior", "gender": "male" }),
+        json!({ "name": "has space", "class": "warrior", "gender": "male" }),
+        json!({ "name": "toolongname123", "class": "warrior", "gender": "male" }),
+        json!({ "name": "Valid", "class": "necromancer", "gender": "male" }),
+        json!({ "name": "Valid", "class": "mage", "gender": "other" }),
+        json!({ "name": "Valid", "class": "mage" }),
+    ];
+    for body in &bodies {
+        let response = create(&state, &session.token, body).await;
+        assert_eq!(response.status, StatusCode::BAD_REQUEST, "{body}");
+    }
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn other_accounts_characters_are_hidden() {
+    let state = state().await;
+    let owner = common::sign_up(&state).await;
+    let intruder = common::sign_up(&state).await;
+    let request = CreateCharacterRequest {
+        name: unique_character_name(),
+        class: CharacterClass::Cleric,
+        gender: Gender::Female,
+    };
+    let character: Character = create(&state, &owner.token, &request).await.json();
+    let uri = format!("/api/characters/{}", character.id);
+
+    assert_eq!(call(&state, Method::GET, &uri, &intruder.token).await.status, StatusCode::NOT_FOUND);
+    assert_eq!(call(&state, Method::DELETE, &uri, &intruder.token).await.status, StatusCode::NOT_FOUND);
+    let listed = call(&state, Method::GET, "/api/characters", &intruder.token).await;
+    assert!(listed.json::<Vec<Character>>().is_empty());
+    assert_eq!(call(&state, Method::GET, &uri, &owner.token).await.status, StatusCode::OK);
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn disabled_accounts_are_locked_out() {
+    let state = state().await;
+    let session = common::sign_up(&state).await;
+    sqlx::query("UPDATE users SET is_active = FALSE WHERE id = $1")
+        .bind(session.user.id)
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+    let response = call(&state, Method::GET, "/api/characters", &session.token).await;
+    assert_eq!(response.status, StatusCode::FORBIDDEN);
+}
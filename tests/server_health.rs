@@ -0,0 +1,66 @@
+//! `/api/health` against an unreachable database, and against the real one from
+//! `DATABASE_URL` (ignored unless run with `--include-ignored`).
+
+#![cfg(feature = "server")]
+
+mod common;
+
+use axum::http::StatusCode;
+use legend_client::server::health::HealthResponse;
+use legend_client::server::{AppState, ServerConfig, ServerError};
+
+#[tokio::test]
+async fn health_reports_unavailable_database() {
+    let response = common::get(&common::unreachable_state(), "/api/health").await;
+    assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+    let health: HealthResponse = response.json();
+    assert_eq!(health.status, "degraded");
+    assert_eq!(health.database, "unavailable");
+    assert_eq!(health.version, env!("CARGO_PKG_VERSION"));
+}
+
+#[tokio::test]
+async fn unknown_route_is_not_found() {
+    let response = common::get(&common::unreachable_state(), "/api/nope").await;
+    assert_eq!(response.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn health_ok_with_database() {
+    let db = common::database().await;
+    let response = common::get(&AppState::new(db, common::JWT_SECRET), "/api/health").await;
+    assert_eq!(response.status, StatusCode::OK);
+    let health: HealthResponse = response.json();
+    assert_eq!(health.database, "ok");
+}
+
+#[test]
+fn config_requires_database_url() {
+    let error = ServerConfig::from_lookup(|_| None).unwrap_err();
+    assert!(matches!(error, ServerError::MissingEnv("DATABASE_URL")));
+}
+
+#[test]
+fn config_requires_jwt_secret() {
+    let error = ServerConfig::from_lookup(|key| (key == "DATABASE_URL").then(|| "postgresql://localhost/legend".into())).unwrap_err();
+    assert!(matches!(error, ServerError::MissingEnv("JWT_SECRET")));
+}
+
+#[test]
+fn config_reads_bind_addr() {
+    let config = ServerConfig::from_lookup(|key| match key {
+        "DATABASE_URL" => Some("postgresql://localhost/legend".into()),
+        "API_ADDR" => Some("127.0.0.1:4000".into()),
+        "JWT_SECRET" => Some("secret".into()),
+        _ => None,
+    })
+    .unwrap();
+    assert_eq!(config.bind_addr.port(), 4000);
+
+    let invalid = ServerConfig::from_lookup(|key| match key {
+        "DATABASE_URL" => Some("postgresql://localhost/legend".into()),
+        _ => Some("not an address".into()),
+    });
+    assert!(matches!(invalid, Err(ServerError::InvalidEnv { key: "API_ADDR", .. })));
+}
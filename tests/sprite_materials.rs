@@ -0,0 +1,52 @@
+#![cfg(feature = "client")]
+
+use bevy::prelude::*;
+use legend_client::client::graphics::{Animation, GraphicsPlugin, SpriteFrameMaterials};
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<StandardMaterial>()
+        .init_asset::<Image>()
+        .add_plugins(GraphicsPlugin);
+    app
+}
+
+fn spawn_sprite(app: &mut App, material: Handle<StandardMaterial>) -> Entity {
+    app.world_mut().spawn((MeshMaterial3d(material), Animation::new(4, 4, 8.0))).id()
+}
+
+#[test]
+fn sprites_on_the_same_frame_share_a_material() {
+    let mut app = app();
+    let source = app.world_mut().resource_mut::<Assets<StandardMaterial>>().add(StandardMaterial::default());
+    let first = spawn_sprite(&mut app, source.clone());
+    let second = spawn_sprite(&mut app, source);
+    app.update();
+    app.update();
+
+    let material = |entity| app.world().get::<MeshMaterial3d<StandardMaterial>>(entity).unwrap().id();
+    assert_eq!(material(first), material(second));
+    assert_eq!(app.world().resource::<SpriteFrameMaterials>().len(), 1);
+}
+
+#[test]
+fn frame_materials_go_away_with_their_sprites() {
+    let mut app = app();
+    let source = app.world_mut().resource_mut::<Assets<StandardMaterial>>().add(StandardMaterial::default());
+    let source_id = source.id();
+    let sprite = spawn_sprite(&mut app, source);
+    app.update();
+    app.update();
+    assert!(!app.world().resource::<SpriteFrameMaterials>().is_empty());
+
+    app.world_mut().despawn(sprite);
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert!(app.world().resource::<SpriteFrameMaterials>().is_empty());
+    let materials = app.world().resource::<Assets<StandardMaterial>>();
+    assert!(materials.get(source_id).is_none(), "source material should be freed");
+    assert!(materials.is_empty(), "frame materials should be freed too");
+}
@@ -0,0 +1,158 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use legend_client::shared::data::characters::{self, CLASSES};
+use legend_client::shared::data::items::{self, ITEMS};
+use legend_client::shared::data::maps::{self, DUNGEONS, MAPS, MapKind};
+use legend_client::shared::data::monsters::{self, MONSTERS};
+use legend_client::shared::data::skills::{self, SKILLS, SKILLS_PER_CLASS};
+use legend_client::shared::domain::character::{CharacterClass, Gender, MAX_LEVEL};
+
+fn asset(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("public/assets").join(path)
+}
+
+fn missing_assets<'a>(paths: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+    paths.into_iter().filter(|path| !asset(path).is_file()).collect()
+}
+
+fn assert_unique<T: std::fmt::Debug>(what: &str, ids: impl IntoIterator<Item = T>) {
+    let mut seen = HashSet::new();
+    for id in ids {
+        assert!(seen.insert(format!("{id:?}")), "duplicate {what} id {id:?}");
+    }
+}
+
+#[test]
+fn every_referenced_asset_exists() {
+    let class_sprites: Vec<String> = CLASSES
+        .iter()
+        .flat_map(|def| [def.sprite(Gender::Male), def.sprite(Gender::Female)])
+        .collect();
+
+    let paths = MONSTERS
+        .iter()
+        .flat_map(|m| std::iter::once(m.sprite).chain(m.manifest))
+        .chain(SKILLS.iter().map(|s| s.icon))
+        .chain(ITEMS.iter().flat_map(|i| std::iter::once(i.icon).chain(i.sprite)))
+        .chain(MAPS.iter().filter_map(|m| m.file))
+        .chain(class_sprites.iter().map(String::as_str));
+
+    let missing = missing_assets(paths);
+    assert!(missing.is_empty(), "missing assets: {missing:?}");
+}
+
+#[test]
+fn ids_are_unique() {
+    assert_unique("monster", MONSTERS.iter().map(|m| m.id));
+    assert_unique("skill", SKILLS.iter().map(|s| s.id));
+    assert_unique("skill key", SKILLS.iter().map(|s| s.key));
+    assert_unique("item", ITEMS.iter().map(|i| i.id));
+    assert_unique("item key", ITEMS.iter().map(|i| i.key));
+    assert_unique("map", MAPS.iter().map(|m| m.id));
+    assert_unique("dungeon", DUNGEONS.iter().map(|d| d.id));
+    assert_unique("class", CLASSES.iter().map(|c| c.class));
+}
+
+#[test]
+fn tables_cover_every_class_and_circle() {
+    assert_eq!(SKILLS.len(), 55);
+    assert_eq!(MONSTERS.len(), 25);
+    assert_eq!(MAPS.len(), 15);
+    assert_eq!(CLASSES.len(), CharacterClass::ALL.len());
+
+    for class in CharacterClass::ALL {
+        assert_eq!(skills::class_skills(class).count(), SKILLS_PER_CLASS, "{class:?}");
+        assert_eq!(characters::class_def(class).class, class);
+    }
+    for circle in 1..=5 {
+        assert_eq!(monsters::monsters_in_circle(circle).count(), 5, "circle {circle}");
+        assert_eq!(maps::maps_in_circle(circle).count(), 3, "circle {circle}");
+    }
+}
+
+#[test]
+fn lookups_find_table_entries() {
+    for skill in SKILLS {
+        assert_eq!(skills::skill_by_id(skill.id), Some(skill));
+        assert_eq!(skills::skill_by_key(skill.key), Some(skill));
+    }
+    for item in ITEMS {
+        assert_eq!(items::item_by_id(item.id), Some(item));
+        assert_eq!(items::item_by_key(item.key), Some(item));
+        assert_eq!(item.slot.is_some(), item.is_equipment(), "{}", item.key);
+    }
+    assert!(skills::skill_by_id(0).is_none());
+    assert!(items::item_by_key("excalibur").is_none());
+    assert!(maps::map_by_id("atlantis").is_none());
+}
+
+#[test]
+fn monsters_are_sorted_by_size_then_hp() {
+    for pair in MONSTERS.windows(2) {
+        let key = |m: &monsters::MonsterDef| (m.size as u8, m.hp);
+        assert!(key(&pair[0]) <= key(&pair[1]), "{} should come after {}", pair[0].id, pair[1].id);
+    }
+}
+
+#[test]
+fn maps_and_dungeons_reference_known_data() {
+    for map in MAPS {
+        for monster in map.monsters {
+            assert!(monsters::monster_by_id(monster).is_some(), "{} spawns unknown monster {monster}", map.id);
+        }
+        assert!(map.min_level <= map.max_level, "{}", map.id);
+        if map.kind == MapKind::Town {
+            assert!(map.monsters.is_empty(), "town {} is a safe zone but spawns monsters", map.id);
+        }
+    }
+
+    for dungeon in DUNGEONS {
+        let map = maps::map_by_id(dungeon.map).unwrap_or_else(|| panic!("dungeon {} has unknown map", dungeon.id));
+        assert_eq!(map.kind, MapKind::Dungeon, "dungeon {}", dungeon.id);
+        assert!(map.monsters.contains(&dungeon.boss), "dungeon {} boss is not in {}", dungeon.id, map.id);
+        assert!(monsters::monster_by_id(dungeon.boss).is_some_and(|m| m.circle == map.circle), "dungeon {} boss circle", dungeon.id);
+        assert_eq!(maps::dungeon_by_id(dungeon.id), Some(dungeon));
+    }
+}
+
+#[test]
+fn map_files_match_their_definitions() {
+    for map in MAPS {
+        let Some(file) = map.file else { continue };
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(asset(file)).unwrap()).unwrap_or_else(|e| panic!("{file}: {e}"));
+        assert_eq!(json["id"], map.id, "{file}");
+
+        let zone_monsters = json["enemy_zones"].as_array().into_iter().flatten().flat_map(|zone| zone["monsters"].as_array().into_iter().flatten());
+        for monster in zone_monsters {
+            let monster = monster.as_str().unwrap();
+            assert!(monsters::monster_by_id(monster).is_some(), "{file} spawns unknown monster {monster}");
+        }
+
+        for portal in json["portals"].as_array().into_iter().flatten() {
+            let target = portal["target_map"].as_str().unwrap();
+            assert!(maps::map_by_id(target).is_some_and(|m| m.file.is_some()), "{file} portal to unknown map {target}");
+        }
+    }
+}
+
+#[test]
+fn exp_curve_follows_the_formula() {
+    assert_eq!(characters::exp_to_next(1), Some(100));
+    assert_eq!(characters::exp_to_next(4), Some(800));
+    assert_eq!(characters::exp_to_next(MAX_LEVEL), None);
+    assert_eq!(characters::exp_to_next(0), None);
+
+    assert_eq!(characters::total_exp_for_level(1), 0);
+    assert_eq!(characters::total_exp_for_level(3), 100 + 282);
+    assert_eq!(characters::level_for_total_exp(0), 1);
+    assert_eq!(characters::level_for_total_exp(381), 2);
+    assert_eq!(characters::level_for_total_exp(382), 3);
+    assert_eq!(characters::level_for_total_exp(i64::MAX), MAX_LEVEL);
+
+    assert_eq!(characters::circle_for_level(1), 1);
+    assert_eq!(characters::circle_for_level(20), 1);
+    assert_eq!(characters::circle_for_level(21), 2);
+    assert_eq!(characters::circle_for_level(MAX_LEVEL), 5);
+}
@@ -0,0 +1,67 @@
+use legend_client::shared::constants::TILE_WORLD_SIZE;
+use legend_client::shared::tilemap::{TileMap, TileType, tile_center_to_world, tile_to_world, world_to_tile};
+
+#[test]
+fn only_water_lava_and_walls_block() {
+    for tile in TileType::ALL {
+        let blocked = matches!(tile, TileType::Water | TileType::Lava | TileType::Wall);
+        assert_eq!(tile.is_walkable(), !blocked, "{tile:?}");
+    }
+}
+
+#[test]
+fn get_and_set_stay_inside_the_map() {
+    let mut tiles = TileMap::new(4, 3, TileType::Grass);
+
+    assert!(tiles.in_bounds(0, 0));
+    assert!(tiles.in_bounds(3, 2));
+    for (x, y) in [(-1, 0), (0, -1), (4, 0), (0, 3)] {
+        assert!(!tiles.in_bounds(x, y), "({x}, {y})");
+        assert_eq!(tiles.get(x, y), None);
+        assert!(!tiles.set(x, y, TileType::Water));
+        assert!(!tiles.is_walkable(x, y), "outside the map is never walkable");
+    }
+
+    assert!(tiles.set(3, 2, TileType::Lava));
+    assert_eq!(tiles.get(3, 2), Some(TileType::Lava));
+    assert!(!tiles.is_walkable(3, 2));
+    // Row-major: the last tile doesn't wrap onto another row
+    assert_eq!(tiles.get(0, 2), Some(TileType::Grass));
+    assert_eq!(tiles.iter().filter(|(_, _, tile)| *tile == TileType::Lava).count(), 1);
+}
+
+#[test]
+fn tile_origin_is_the_world_origin() {
+    assert_eq!(tile_to_world(0, 0), (0.0, 0.0));
+    assert_eq!(tile_to_world(3, -2), (3.0 * TILE_WORLD_SIZE, -2.0 * TILE_WORLD_SIZE));
+    assert_eq!(tile_center_to_world(0, 0), (TILE_WORLD_SIZE * 0.5, TILE_WORLD_SIZE * 0.5));
+
+    let tiles = TileMap::new(10, 6, TileType::Grass);
+    assert_eq!(tiles.world_size(), (10.0 * TILE_WORLD_SIZE, 6.0 * TILE_WORLD_SIZE));
+    assert_eq!(tiles.world_center(), (5.0 * TILE_WORLD_SIZE, 3.0 * TILE_WORLD_SIZE));
+    // Conversions don't depend on the map size
+    assert_eq!(tiles.tile_to_world(3, -2), tile_to_world(3, -2));
+    assert_eq!(tiles.world_to_tile(7.3, -0.2), world_to_tile(7.3, -0.2));
+}
+
+#[test]
+fn world_to_tile_floors_at_edges_and_below_zero() {
+    assert_eq!(world_to_tile(0.0, 0.0), (0, 0));
+    assert_eq!(world_to_tile(TILE_WORLD_SIZE - 0.001, 0.0), (0, 0));
+    assert_eq!(world_to_tile(TILE_WORLD_SIZE, TILE_WORLD_SIZE), (1, 1));
+    // Just left of the origin is tile -1, not 0 (floor, not truncation)
+    assert_eq!(world_to_tile(-0.001, -0.001), (-1, -1));
+    assert_eq!(world_to_tile(-TILE_WORLD_SIZE, -TILE_WORLD_SIZE - 0.001), (-1, -2));
+}
+
+#[test]
+fn tile_and_world_round_trip() {
+    for y in -5..5 {
+        for x in -5..5 {
+            let (corner_x, corner_z) = tile_to_world(x, y);
+            let (center_x, center_z) = tile_center_to_world(x, y);
+            assert_eq!(world_to_tile(corner_x, corner_z), (x, y), "corner of ({x}, {y})");
+            assert_eq!(world_to_tile(center_x, center_z), (x, y), "centre of ({x}, {y})");
+        }
+    }
+}
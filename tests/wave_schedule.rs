@@ -0,0 +1,132 @@
+#![cfg(feature = "client")]
+
+use std::path::Path;
+
+use bevy::math::Vec3;
+use legend_client::client::wave::{SpawnPoint, WaveSchedule};
+use legend_client::shared::data::monsters::monster_by_id;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+
+fn survival() -> WaveSchedule {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("public/assets/waves/survival.json");
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn schedule(json: serde_json::Value) -> WaveSchedule {
+    serde_json::from_value(json).unwrap()
+}
+
+/// Three single-group waves of 10 rats, optionally looping from `repeat_from`
+fn three_waves(endless: Option<(usize, f32)>) -> WaveSchedule {
+    let wave = |monster: &str| serde_json::json!({ "groups": [{ "monster": monster, "count": 10 }] });
+    let mut json = serde_json::json!({
+        "first_wave_delay": 10.0,
+        "wave_interval": 20.0,
+        "spawn_radius": 15.0,
+        "waves": [wave("rat"), wave("bat"), wave("wolf")],
+    });
+    if let Some((repeat_from, count_growth)) = endless {
+        json["endless"] = serde_json::json!({ "repeat_from": repeat_from, "count_growth": count_growth });
+    }
+    schedule(json)
+}
+
+fn monster(schedule: &WaveSchedule, number: u32) -> Option<(&str, f32)> {
+    schedule
+        .wave(number)
+        .map(|(wave, multiplier)| (wave.groups[0].monster.as_str(), multiplier))
+}
+
+#[test]
+fn survival_schedule_parses_and_every_monster_exists() {
+    let schedule = survival();
+    assert!(!schedule.waves.is_empty());
+    assert!(schedule.endless.is_some(), "survival should never run out of waves");
+
+    for (number, wave) in schedule.waves.iter().enumerate() {
+        assert!(!wave.groups.is_empty(), "wave {} has no groups", number + 1);
+        for group in &wave.groups {
+            assert!(monster_by_id(&group.monster).is_some(), "wave {}: unknown monster '{}'", number + 1, group.monster);
+            assert!(group.count > 0, "wave {}: empty group of {}", number + 1, group.monster);
+        }
+    }
+}
+
+#[test]
+fn listed_waves_are_one_based_and_unscaled() {
+    let schedule = three_waves(None);
+
+    assert_eq!(monster(&schedule, 0), None);
+    assert_eq!(monster(&schedule, 1), Some(("rat", 1.0)));
+    assert_eq!(monster(&schedule, 3), Some(("wolf", 1.0)));
+}
+
+#[test]
+fn schedule_without_endless_rule_ends() {
+    assert_eq!(monster(&three_waves(None), 4), None);
+}
+
+#[test]
+fn endless_rule_repeats_the_tail_with_growing_counts() {
+    let schedule = three_waves(Some((2, 0.5)));
+
+    assert_eq!(monster(&schedule, 4), Some(("bat", 1.5)));
+    assert_eq!(monster(&schedule, 5), Some(("wolf", 1.5)));
+    assert_eq!(monster(&schedule, 6), Some(("bat", 2.0)));
+    assert_eq!(monster(&schedule, 7), Some(("wolf", 2.0)));
+}
+
+#[test]
+fn endless_repeat_from_is_clamped_to_the_listed_waves() {
+    let from_zero = three_waves(Some((0, 1.0)));
+    assert_eq!(monster(&from_zero, 4), Some(("rat", 2.0)));
+
+    let past_end = three_waves(Some((9, 1.0)));
+    assert_eq!(monster(&past_end, 4), Some(("wolf", 2.0)));
+    assert_eq!(monster(&past_end, 5), Some(("wolf", 3.0)));
+}
+
+#[test]
+fn ring_spreads_monsters_evenly_on_the_circle() {
+    let mut rng = SmallRng::seed_from_u64(1);
+
+    let first = SpawnPoint::Ring.position(0, 4, 10.0, &mut rng);
+    let second = SpawnPoint::Ring.position(1, 4, 10.0, &mut rng);
+    assert!(first.distance(Vec3::new(10.0, 0.0, 0.0)) < 1e-4);
+    assert!(second.distance(Vec3::new(0.0, 0.0, 10.0)) < 1e-4);
+}
+
+#[test]
+fn sides_and_random_stay_on_their_edge() {
+    let mut rng = SmallRng::seed_from_u64(7);
+    for index in 0..20 {
+        let north = SpawnPoint::North.position(index, 20, 10.0, &mut rng);
+        assert_eq!(north.z, -10.0);
+        assert!(north.x.abs() <= 10.0);
+
+        let west = SpawnPoint::West.position(index, 20, 10.0, &mut rng);
+        assert_eq!(west.x, -10.0);
+        assert!(west.z.abs() <= 10.0);
+
+        let random = SpawnPoint::Random.position(index, 20, 10.0, &mut rng);
+        assert!((random.length() - 10.0).abs() < 1e-3);
+        assert_eq!(random.y, 0.0);
+    }
+}
+
+#[test]
+fn point_ignores_radius_and_rng() {
+    let mut rng = SmallRng::seed_from_u64(3);
+    let position = SpawnPoint::Point([2.5, -4.0]).position(0, 1, 99.0, &mut rng);
+    assert_eq!(position, Vec3::new(2.5, 0.0, -4.0));
+}
+
+#[test]
+fn same_seed_gives_same_positions() {
+    let positions = |seed| {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        (0..8).map(|index| SpawnPoint::Random.position(index, 8, 12.0, &mut rng)).collect::<Vec<_>>()
+    };
+    assert_eq!(positions(42), positions(42));
+}